@@ -0,0 +1,242 @@
+//! Hypernode Reputation
+//!
+//! One deterministic, integer-only reputation formula shared by the
+//! on-chain programs (no floats, so every validator computes the same
+//! score).
+//!
+//! Score (0-1000) = weighted component rates - penalties - inactivity decay
+//!
+//! Components (basis points, 0-10000):
+//! - Job success rate (40%), smoothed toward 50% while few jobs are known
+//! - Health-check pass rate (30%), smoothed the same way
+//! - Uptime over the tracking window (30%)
+//!
+//! Penalties (points):
+//! - Failed audit: -100 each
+//! - Failed compute challenge: -50 each
+//!
+//! Outstanding penalty points halve every 30 days, so a node that stops
+//! failing recovers its score.
+//!
+//! Decay: -10 points per full week since the node was last active.
+//!
+//! A brand-new node (no jobs, no checks, 100% uptime) scores 650; with a
+//! neutral 50% uptime (no uptime data) it scores 500.
+
+#![no_std]
+
+/// Maximum score
+pub const MAX_SCORE: u16 = 1000;
+
+/// Basis point denominator
+pub const BPS: u64 = 10_000;
+
+/// Component weights (bps, sum to BPS)
+pub const JOB_WEIGHT_BPS: u64 = 4_000;
+pub const HEALTH_WEIGHT_BPS: u64 = 3_000;
+pub const UPTIME_WEIGHT_BPS: u64 = 3_000;
+
+/// Pseudo-observations (half successes) added to each rate so a single
+/// early job or check can't swing the score to 0 or 1000
+pub const PRIOR_SAMPLES: u64 = 10;
+
+/// Points lost per failed audit
+pub const AUDIT_FAILURE_PENALTY: u64 = 100;
+
+/// Points lost per failed compute challenge
+pub const CHALLENGE_FAILURE_PENALTY: u64 = 50;
+
+/// Seconds for outstanding penalty points to halve
+pub const PENALTY_HALF_LIFE_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Points lost per full week of inactivity
+pub const DECAY_PER_WEEK: u64 = 10;
+
+/// Seconds per decay period
+pub const WEEK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Everything the score is computed from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReputationInputs {
+    pub jobs_completed: u64,
+    pub jobs_failed: u64,
+    pub health_checks_passed: u64,
+    pub health_checks_failed: u64,
+    /// 0-100
+    pub uptime_percentage: u8,
+    /// Outstanding audit/challenge penalty points (see `add_penalty`)
+    pub penalty_points: u64,
+    /// When `penalty_points` was last updated
+    pub penalty_updated_at: i64,
+    /// Last time the node was seen (e.g. heartbeat)
+    pub last_active_at: i64,
+    /// Evaluation time
+    pub now: i64,
+}
+
+/// Success rate in bps, smoothed toward 50% by `PRIOR_SAMPLES`
+pub fn smoothed_rate_bps(successes: u64, failures: u64) -> u64 {
+    let total = successes.saturating_add(failures).saturating_add(PRIOR_SAMPLES);
+    let successes = successes.saturating_add(PRIOR_SAMPLES / 2);
+    (successes as u128 * BPS as u128 / total as u128) as u64
+}
+
+/// Points lost to inactivity at `now`
+pub fn decay(last_active_at: i64, now: i64) -> u64 {
+    let inactive = now.saturating_sub(last_active_at).max(0);
+    (inactive / WEEK_SECONDS) as u64 * DECAY_PER_WEEK
+}
+
+/// Points lost to audit and challenge failures
+pub fn penalties(audit_failures: u32, challenge_failures: u32) -> u64 {
+    (audit_failures as u64)
+        .saturating_mul(AUDIT_FAILURE_PENALTY)
+        .saturating_add((challenge_failures as u64).saturating_mul(CHALLENGE_FAILURE_PENALTY))
+}
+
+/// Penalty points left at `now` from `points` outstanding at `since`
+///
+/// Halves every `PENALTY_HALF_LIFE_SECONDS`, linearly within a half-life.
+pub fn decayed_penalty(points: u64, since: i64, now: i64) -> u64 {
+    let elapsed = now.saturating_sub(since).max(0);
+    let halvings = elapsed / PENALTY_HALF_LIFE_SECONDS;
+    if halvings >= 64 {
+        return 0;
+    }
+
+    let remaining = points >> halvings;
+    let partial = elapsed % PENALTY_HALF_LIFE_SECONDS;
+    let decayed = (remaining / 2) as u128 * partial as u128 / PENALTY_HALF_LIFE_SECONDS as u128;
+    remaining - decayed as u64
+}
+
+/// Outstanding penalty points after adding `points` at `now`
+///
+/// Returns the new total; store it with `now` as the update time.
+pub fn add_penalty(outstanding: u64, since: i64, now: i64, points: u64) -> u64 {
+    decayed_penalty(outstanding, since, now).saturating_add(points)
+}
+
+/// Reputation score (0-1000)
+pub fn score(inputs: &ReputationInputs) -> u16 {
+    let job_bps = smoothed_rate_bps(inputs.jobs_completed, inputs.jobs_failed);
+    let health_bps = smoothed_rate_bps(inputs.health_checks_passed, inputs.health_checks_failed);
+    let uptime_bps = (inputs.uptime_percentage.min(100) as u64) * 100;
+
+    let weighted_bps = (job_bps * JOB_WEIGHT_BPS
+        + health_bps * HEALTH_WEIGHT_BPS
+        + uptime_bps * UPTIME_WEIGHT_BPS)
+        / BPS;
+    let base = weighted_bps * MAX_SCORE as u64 / BPS;
+
+    let deductions = decayed_penalty(inputs.penalty_points, inputs.penalty_updated_at, inputs.now)
+        .saturating_add(decay(inputs.last_active_at, inputs.now));
+
+    base.saturating_sub(deductions).min(MAX_SCORE as u64) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn inputs(uptime_percentage: u8) -> ReputationInputs {
+        ReputationInputs {
+            uptime_percentage,
+            now: 1_700_000_000,
+            last_active_at: 1_700_000_000,
+            penalty_updated_at: 1_700_000_000,
+            ..ReputationInputs::default()
+        }
+    }
+
+    #[test]
+    fn new_node_scores() {
+        assert_eq!(score(&inputs(100)), 650);
+        assert_eq!(score(&inputs(50)), 500);
+        assert_eq!(score(&inputs(0)), 350);
+    }
+
+    #[test]
+    fn smoothing_prior() {
+        assert_eq!(smoothed_rate_bps(0, 0), 5_000);
+        assert_eq!(smoothed_rate_bps(1, 0), 5_454);
+        assert_eq!(smoothed_rate_bps(0, 1), 4_545);
+        assert_eq!(smoothed_rate_bps(95, 5), 9_090);
+    }
+
+    #[test]
+    fn saturates_at_max() {
+        let perfect = ReputationInputs {
+            jobs_completed: u64::MAX,
+            health_checks_passed: u64::MAX,
+            ..inputs(255)
+        };
+        let top = score(&perfect);
+        assert!((MAX_SCORE - 1..=MAX_SCORE).contains(&top));
+    }
+
+    #[test]
+    fn saturates_at_zero() {
+        let failing = ReputationInputs {
+            jobs_failed: 1_000,
+            health_checks_failed: 1_000,
+            penalty_points: u64::MAX,
+            ..inputs(0)
+        };
+        assert_eq!(score(&failing), 0);
+
+        let abandoned = ReputationInputs {
+            now: i64::MAX,
+            ..inputs(100)
+        };
+        assert_eq!(score(&abandoned), 0);
+    }
+
+    #[test]
+    fn weekly_decay() {
+        let base = inputs(100);
+        let at = |elapsed: i64| {
+            score(&ReputationInputs {
+                now: base.now + elapsed,
+                ..base
+            })
+        };
+
+        assert_eq!(at(6 * DAY), 650);
+        assert_eq!(at(WEEK_SECONDS), 640);
+        assert_eq!(at(3 * WEEK_SECONDS + DAY), 620);
+        assert_eq!(decay(base.now, base.now - WEEK_SECONDS), 0);
+    }
+
+    #[test]
+    fn penalties_halve() {
+        assert_eq!(penalties(1, 2), 200);
+        assert_eq!(decayed_penalty(100, 0, 0), 100);
+        assert_eq!(decayed_penalty(100, 0, PENALTY_HALF_LIFE_SECONDS / 2), 75);
+        assert_eq!(decayed_penalty(100, 0, PENALTY_HALF_LIFE_SECONDS), 50);
+        assert_eq!(decayed_penalty(100, 0, 2 * PENALTY_HALF_LIFE_SECONDS), 25);
+        assert_eq!(decayed_penalty(100, 0, 64 * PENALTY_HALF_LIFE_SECONDS), 0);
+        assert_eq!(decayed_penalty(100, 10, 0), 100);
+        assert_eq!(add_penalty(100, 0, PENALTY_HALF_LIFE_SECONDS, 50), 100);
+    }
+
+    #[test]
+    fn penalties_lower_score_and_recover() {
+        let base = inputs(100);
+        let penalized = ReputationInputs {
+            penalty_points: penalties(1, 0),
+            ..base
+        };
+        assert_eq!(score(&penalized), 550);
+
+        // Active node: only the penalty decays
+        let later = ReputationInputs {
+            now: base.now + PENALTY_HALF_LIFE_SECONDS,
+            last_active_at: base.now + PENALTY_HALF_LIFE_SECONDS,
+            ..penalized
+        };
+        assert_eq!(score(&later), 600);
+    }
+}
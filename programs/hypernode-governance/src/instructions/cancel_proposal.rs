@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Cancel a proposal before execution (proposer only)
+pub fn cancel_proposal(ctx: Context<CancelProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        matches!(
+            proposal.status,
+            ProposalStatus::Voting | ProposalStatus::Queued
+        ),
+        GovernanceError::CannotCancel
+    );
+
+    proposal.status = ProposalStatus::Cancelled;
+
+    msg!("Proposal {} cancelled", proposal.id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer @ GovernanceError::NotProposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub proposer: Signer<'info>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::{Staker, StakingConfig};
+use crate::state::*;
+
+/// Vote on a proposal with voting power at its snapshot
+///
+/// All active stake positions must be passed as remaining accounts.
+/// One vote per wallet per proposal (VoteRecord PDA).
+pub fn cast_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+    _proposal_id: u64,
+    support: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: voting open
+    require!(
+        proposal.status == ProposalStatus::Voting,
+        GovernanceError::NotVoting
+    );
+    require!(now < proposal.voting_ends_at, GovernanceError::VotingEnded);
+
+    // Voting power at snapshot
+    let weight = ctx.accounts.staker.voting_power(
+        &ctx.accounts.staking_config,
+        ctx.remaining_accounts,
+        proposal.snapshot_at,
+    )?;
+    require!(weight > 0, GovernanceError::NoVotingPower);
+
+    if support {
+        proposal.for_votes = proposal
+            .for_votes
+            .checked_add(weight)
+            .ok_or(GovernanceError::CalculationOverflow)?;
+    } else {
+        proposal.against_votes = proposal
+            .against_votes
+            .checked_add(weight)
+            .ok_or(GovernanceError::CalculationOverflow)?;
+    }
+
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.support = support;
+    vote_record.weight = weight;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    msg!(
+        "{} voted {} on proposal {} with {} xNOS",
+        vote_record.voter,
+        if support { "for" } else { "against" },
+        proposal.id,
+        weight
+    );
+
+    emit!(VoteCastEvent {
+        proposal_id: proposal.id,
+        voter: vote_record.voter,
+        support,
+        weight,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::SPACE,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// Staking configuration (decay mode)
+    #[account(
+        seeds = [b"config"],
+        bump = staking_config.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Voter's staking aggregate
+    #[account(
+        seeds = [b"staker", voter.key().as_ref()],
+        bump = staker.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staker: Account<'info, Staker>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct VoteCastEvent {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+}
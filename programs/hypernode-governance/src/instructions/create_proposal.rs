@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::{Staker, StakingConfig};
+use crate::state::*;
+
+/// Create a proposal carrying one instruction to execute
+///
+/// - Proposer needs proposal_threshold voting power (all active stake
+///   positions passed as remaining accounts)
+/// - Voting power is snapshotted one second before creation, so positions
+///   opened afterwards cannot vote
+pub fn create_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateProposal<'info>>,
+    target_program: Pubkey,
+    accounts: Vec<ProposalAccount>,
+    data: Vec<u8>,
+    description: String,
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: instruction fits the proposal account
+    require!(
+        accounts.len() <= Proposal::MAX_ACCOUNTS,
+        GovernanceError::TooManyAccounts
+    );
+    require!(
+        data.len() <= Proposal::MAX_DATA_LEN,
+        GovernanceError::DataTooLong
+    );
+    require!(
+        description.len() <= Proposal::MAX_DESCRIPTION_LEN,
+        GovernanceError::DescriptionTooLong
+    );
+
+    // Validation: proposer voting power
+    let voting_power = ctx.accounts.staker.voting_power(
+        &ctx.accounts.staking_config,
+        ctx.remaining_accounts,
+        now,
+    )?;
+    require!(
+        voting_power >= governance.proposal_threshold,
+        GovernanceError::BelowProposalThreshold
+    );
+
+    proposal.id = governance.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.target_program = target_program;
+    proposal.accounts = accounts;
+    proposal.data = data;
+    proposal.description = description;
+    proposal.snapshot_at = now - 1;
+    proposal.voting_ends_at = now
+        .checked_add(governance.voting_period)
+        .ok_or(GovernanceError::CalculationOverflow)?;
+    proposal.eta = None;
+    proposal.for_votes = 0;
+    proposal.against_votes = 0;
+    proposal.status = ProposalStatus::Voting;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance
+        .proposal_count
+        .checked_add(1)
+        .ok_or(GovernanceError::CalculationOverflow)?;
+
+    msg!("Proposal {} created by {}", proposal.id, proposal.proposer);
+    msg!("Target program: {}", proposal.target_program);
+    msg!("Voting ends at: {}", proposal.voting_ends_at);
+
+    emit!(ProposalCreatedEvent {
+        proposal_id: proposal.id,
+        proposer: proposal.proposer,
+        target_program,
+        voting_ends_at: proposal.voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::SPACE,
+        seeds = [b"proposal", &governance.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Staking configuration (decay mode)
+    #[account(
+        seeds = [b"config"],
+        bump = staking_config.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Proposer's staking aggregate
+    #[account(
+        seeds = [b"staker", proposer.key().as_ref()],
+        bump = staker.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staker: Account<'info, Staker>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub target_program: Pubkey,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
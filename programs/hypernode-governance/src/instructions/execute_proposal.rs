@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+
+/// Execute a queued proposal after its timelock (permissionless)
+///
+/// Sends the stored instruction with the governance PDA as signer.
+/// Every account of the instruction, and the target program, must be
+/// passed as remaining accounts.
+pub fn execute_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+    _proposal_id: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validation: queued and timelock expired
+    require!(
+        proposal.status == ProposalStatus::Queued,
+        GovernanceError::NotQueued
+    );
+    let eta = proposal.eta.ok_or(GovernanceError::NotQueued)?;
+    require!(now >= eta, GovernanceError::TimelockNotExpired);
+
+    // Validation: all instruction accounts supplied
+    let governance_info = ctx.accounts.governance.to_account_info();
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(governance_info);
+
+    require!(
+        account_infos
+            .iter()
+            .any(|info| info.key() == proposal.target_program),
+        GovernanceError::MissingAccount
+    );
+    for meta in proposal.accounts.iter() {
+        require!(
+            account_infos.iter().any(|info| info.key() == meta.pubkey),
+            GovernanceError::MissingAccount
+        );
+    }
+
+    // Mark executed before the call (no replay through re-entry)
+    proposal.status = ProposalStatus::Executed;
+
+    let instruction = proposal.instruction();
+    let seeds = &[b"governance".as_ref(), &[ctx.bumps.governance]];
+    let signer = &[&seeds[..]];
+
+    invoke_signed(&instruction, &account_infos, signer)?;
+
+    msg!("Proposal {} executed", proposal.id);
+
+    emit!(ProposalExecutedEvent {
+        proposal_id: proposal.id,
+        target_program: proposal.target_program,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    /// CHECK: Governance PDA signing the instruction. Kept unchecked so
+    /// parameter changes made by the instruction are not overwritten.
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub target_program: Pubkey,
+    pub timestamp: i64,
+}
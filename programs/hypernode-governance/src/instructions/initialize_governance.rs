@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::program::HypernodeGovernance;
+
+/// Initialize governance configuration
+///
+/// Creates the GovernanceConfig PDA. Only the program's upgrade authority
+/// may initialize, so parameters can't be set by whoever calls first after
+/// deploy. Afterwards they can only be changed by an executed proposal
+/// (`update_governance`).
+pub fn initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    params: GovernanceParams,
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+
+    governance.voting_period = params.voting_period;
+    governance.timelock_delay = params.timelock_delay;
+    governance.quorum_votes = params.quorum_votes;
+    governance.proposal_threshold = params.proposal_threshold;
+    governance.proposal_count = 0;
+    governance.bump = ctx.bumps.governance;
+
+    require!(governance.is_valid(), GovernanceError::InvalidParams);
+
+    msg!("Governance initialized: {}", governance.key());
+    msg!("Voting period: {} seconds", governance.voting_period);
+    msg!("Timelock delay: {} seconds", governance.timelock_delay);
+    msg!("Quorum: {} xNOS", governance.quorum_votes);
+    msg!("Proposal threshold: {} xNOS", governance.proposal_threshold);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    /// Governance configuration (PDA, also the executing authority)
+    #[account(
+        init,
+        payer = payer,
+        space = GovernanceConfig::SPACE,
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    /// Program upgrade authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ GovernanceError::NotUpgradeAuthority
+    )]
+    pub program: Program<'info, HypernodeGovernance>,
+
+    /// Upgrade data of this program (holds the upgrade authority)
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key())
+            @ GovernanceError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GovernanceParams {
+    pub voting_period: i64,
+    pub timelock_delay: i64,
+    pub quorum_votes: u64,
+    pub proposal_threshold: u64,
+}
@@ -0,0 +1,15 @@
+pub mod initialize_governance;
+pub mod update_governance;
+pub mod create_proposal;
+pub mod cast_vote;
+pub mod queue_proposal;
+pub mod execute_proposal;
+pub mod cancel_proposal;
+
+pub use initialize_governance::*;
+pub use update_governance::*;
+pub use create_proposal::*;
+pub use cast_vote::*;
+pub use queue_proposal::*;
+pub use execute_proposal::*;
+pub use cancel_proposal::*;
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Tally a proposal after voting ends (permissionless)
+///
+/// - Passed (for > against and quorum reached): queued, eta = now + timelock
+/// - Otherwise: defeated
+pub fn queue_proposal(ctx: Context<QueueProposal>, _proposal_id: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        proposal.status == ProposalStatus::Voting,
+        GovernanceError::NotVoting
+    );
+    require!(
+        now >= proposal.voting_ends_at,
+        GovernanceError::VotingNotEnded
+    );
+
+    if proposal.passed(governance.quorum_votes) {
+        let eta = now
+            .checked_add(governance.timelock_delay)
+            .ok_or(GovernanceError::CalculationOverflow)?;
+        proposal.status = ProposalStatus::Queued;
+        proposal.eta = Some(eta);
+        msg!("Proposal {} passed, executable at {}", proposal.id, eta);
+    } else {
+        proposal.status = ProposalStatus::Defeated;
+        msg!("Proposal {} defeated", proposal.id);
+    }
+
+    msg!(
+        "Votes: {} for, {} against (quorum {})",
+        proposal.for_votes,
+        proposal.against_votes,
+        governance.quorum_votes
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct QueueProposal<'info> {
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", &proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Update governance parameters (governance PDA only)
+///
+/// Only callable through an executed proposal targeting this program.
+/// Every field is optional; only provided values are changed.
+pub fn update_governance(
+    ctx: Context<UpdateGovernance>,
+    params: UpdateGovernanceParams,
+) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+
+    if let Some(voting_period) = params.voting_period {
+        governance.voting_period = voting_period;
+    }
+    if let Some(timelock_delay) = params.timelock_delay {
+        governance.timelock_delay = timelock_delay;
+    }
+    if let Some(quorum_votes) = params.quorum_votes {
+        governance.quorum_votes = quorum_votes;
+    }
+    if let Some(proposal_threshold) = params.proposal_threshold {
+        governance.proposal_threshold = proposal_threshold;
+    }
+
+    require!(governance.is_valid(), GovernanceError::InvalidParams);
+
+    msg!("Governance updated");
+    msg!("Voting period: {} seconds", governance.voting_period);
+    msg!("Timelock delay: {} seconds", governance.timelock_delay);
+    msg!("Quorum: {} xNOS", governance.quorum_votes);
+    msg!("Proposal threshold: {} xNOS", governance.proposal_threshold);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGovernance<'info> {
+    /// Governance configuration; must sign (via `execute_proposal`)
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = governance.to_account_info().is_signer @ GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+}
+
+/// Optional governance changes (None = keep current value)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateGovernanceParams {
+    pub voting_period: Option<i64>,
+    pub timelock_delay: Option<i64>,
+    pub quorum_votes: Option<u64>,
+    pub proposal_threshold: Option<u64>,
+}
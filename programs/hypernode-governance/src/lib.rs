@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+pub mod state;
+pub mod instructions;
+
+use instructions::*;
+use state::ProposalAccount;
+
+declare_id!("HYPRgov111111111111111111111111111111111111");
+
+/// Hypernode Governance Program
+///
+/// Lets xNOS holders control protocol parameters.
+///
+/// Core Features:
+/// - Proposals carrying one encoded instruction for any program
+///   (staking config, splitter shares, markets, governance itself)
+/// - Voting with xNOS from hypernode-staking, measured at a snapshot
+/// - Majority + quorum, then a timelock before execution
+/// - Execution signed by the governance PDA (`[b"governance"]`), which is
+///   set as the authority of the governed programs
+///
+/// Architecture Principles:
+/// - Trustless: No admin after initialization
+/// - Modular: Reads staking state, calls programs generically
+/// - Safe: Snapshot voting, one vote per wallet, timelocked execution
+#[program]
+pub mod hypernode_governance {
+    use super::*;
+
+    /// Initialize governance parameters
+    ///
+    /// Called once during deployment. Afterwards parameters change only
+    /// through executed proposals.
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        params: GovernanceParams,
+    ) -> Result<()> {
+        instructions::initialize_governance(ctx, params)
+    }
+
+    /// Update governance parameters (governance PDA only)
+    pub fn update_governance(
+        ctx: Context<UpdateGovernance>,
+        params: UpdateGovernanceParams,
+    ) -> Result<()> {
+        instructions::update_governance(ctx, params)
+    }
+
+    /// Create a proposal (requires proposal_threshold xNOS)
+    ///
+    /// Stake positions passed as remaining accounts.
+    pub fn create_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateProposal<'info>>,
+        target_program: Pubkey,
+        accounts: Vec<ProposalAccount>,
+        data: Vec<u8>,
+        description: String,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, target_program, accounts, data, description)
+    }
+
+    /// Vote for or against a proposal with snapshot voting power
+    ///
+    /// Stake positions passed as remaining accounts.
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+        proposal_id: u64,
+        support: bool,
+    ) -> Result<()> {
+        instructions::cast_vote(ctx, proposal_id, support)
+    }
+
+    /// Tally a proposal after voting ends (queue or defeat)
+    pub fn queue_proposal(ctx: Context<QueueProposal>, proposal_id: u64) -> Result<()> {
+        instructions::queue_proposal(ctx, proposal_id)
+    }
+
+    /// Execute a queued proposal after its timelock
+    ///
+    /// Instruction accounts and target program passed as remaining accounts.
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::execute_proposal(ctx, proposal_id)
+    }
+
+    /// Cancel a proposal before execution (proposer only)
+    pub fn cancel_proposal(ctx: Context<CancelProposal>, proposal_id: u64) -> Result<()> {
+        instructions::cancel_proposal(ctx, proposal_id)
+    }
+}
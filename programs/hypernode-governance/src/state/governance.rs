@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// Global governance configuration
+///
+/// The account is also the governance PDA (`[b"governance"]`) that signs
+/// executed proposals. Set it as the authority of the other programs
+/// (staking config, reward pool, payment splitter, markets) to put them
+/// under xNOS holder control.
+#[account]
+pub struct GovernanceConfig {
+    /// Voting window after a proposal is created (seconds)
+    pub voting_period: i64,
+
+    /// Delay between a proposal passing and becoming executable (seconds)
+    pub timelock_delay: i64,
+
+    /// Minimum total votes (for + against, in xNOS) for a valid outcome
+    pub quorum_votes: u64,
+
+    /// Minimum voting power (xNOS) required to create a proposal
+    pub proposal_threshold: u64,
+
+    /// Number of proposals created (next proposal id)
+    pub proposal_count: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GovernanceConfig {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // voting_period
+        8 + // timelock_delay
+        8 + // quorum_votes
+        8 + // proposal_threshold
+        8 + // proposal_count
+        1; // bump
+
+    /// Default voting period: 5 days
+    pub const DEFAULT_VOTING_PERIOD: i64 = 5 * 24 * 60 * 60;
+
+    /// Default timelock: 2 days
+    pub const DEFAULT_TIMELOCK_DELAY: i64 = 2 * 24 * 60 * 60;
+
+    /// Shortest voting period: 1 day (holders must get a chance to vote)
+    pub const MIN_VOTING_PERIOD: i64 = 24 * 60 * 60;
+
+    /// Shortest timelock: 1 day (time to react before execution)
+    pub const MIN_TIMELOCK_DELAY: i64 = 24 * 60 * 60;
+
+    /// Smallest quorum: 10,000 xNOS (9 decimals)
+    pub const MIN_QUORUM_VOTES: u64 = 10_000 * 1_000_000_000;
+
+    /// Validate parameters
+    pub fn is_valid(&self) -> bool {
+        self.voting_period >= Self::MIN_VOTING_PERIOD
+            && self.timelock_delay >= Self::MIN_TIMELOCK_DELAY
+            && self.quorum_votes >= Self::MIN_QUORUM_VOTES
+    }
+}
+
+/// Errors for governance operations
+#[error_code]
+pub enum GovernanceError {
+    #[msg("Calculation overflow")]
+    CalculationOverflow,
+
+    #[msg("Invalid governance parameters")]
+    InvalidParams,
+
+    #[msg("Only the governance PDA can perform this action")]
+    Unauthorized,
+
+    #[msg("Voting power below proposal threshold")]
+    BelowProposalThreshold,
+
+    #[msg("Too many accounts in proposal instruction")]
+    TooManyAccounts,
+
+    #[msg("Proposal instruction data too long")]
+    DataTooLong,
+
+    #[msg("Proposal description too long")]
+    DescriptionTooLong,
+
+    #[msg("Proposal is not in voting")]
+    NotVoting,
+
+    #[msg("Voting period has ended")]
+    VotingEnded,
+
+    #[msg("Voting period has not ended")]
+    VotingNotEnded,
+
+    #[msg("No voting power at proposal snapshot")]
+    NoVotingPower,
+
+    #[msg("Proposal is not queued")]
+    NotQueued,
+
+    #[msg("Timelock has not expired")]
+    TimelockNotExpired,
+
+    #[msg("Proposal can no longer be cancelled")]
+    CannotCancel,
+
+    #[msg("Only the proposer can cancel")]
+    NotProposer,
+
+    #[msg("Missing account for proposal instruction")]
+    MissingAccount,
+
+    #[msg("Only the program upgrade authority can initialize governance")]
+    NotUpgradeAuthority,
+}
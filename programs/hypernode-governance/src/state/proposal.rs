@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+/// Proposal lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Voting,
+    Queued,
+    Executed,
+    Defeated,
+    Cancelled,
+}
+
+/// Account meta of a proposal instruction (serializable AccountMeta)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ProposalAccount {
+    pub const SPACE: usize = 32 + 1 + 1;
+}
+
+/// A governance proposal carrying one encoded instruction
+///
+/// The instruction targets any program (e.g. staking `update_config`,
+/// splitter `update_config`) and is executed with the governance PDA as
+/// signer once the proposal passes and its timelock expires.
+#[account]
+pub struct Proposal {
+    /// Sequential id (PDA seed)
+    pub id: u64,
+
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+
+    /// Program the instruction is sent to
+    pub target_program: Pubkey,
+
+    /// Instruction accounts (governance PDA appears as signer)
+    pub accounts: Vec<ProposalAccount>,
+
+    /// Instruction data (Anchor discriminator + args)
+    pub data: Vec<u8>,
+
+    /// Human-readable summary or IPFS CID of the full text
+    pub description: String,
+
+    /// Voting power is measured at this timestamp
+    pub snapshot_at: i64,
+
+    /// Voting closes at this timestamp
+    pub voting_ends_at: i64,
+
+    /// Executable from this timestamp once queued
+    pub eta: Option<i64>,
+
+    /// Vote totals (xNOS)
+    pub for_votes: u64,
+    pub against_votes: u64,
+
+    /// Current status
+    pub status: ProposalStatus,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const MAX_ACCOUNTS: usize = 16;
+    pub const MAX_DATA_LEN: usize = 512;
+    pub const MAX_DESCRIPTION_LEN: usize = 200;
+
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // id
+        32 + // proposer
+        32 + // target_program
+        4 + Self::MAX_ACCOUNTS * ProposalAccount::SPACE + // accounts
+        4 + Self::MAX_DATA_LEN + // data
+        4 + Self::MAX_DESCRIPTION_LEN + // description
+        8 + // snapshot_at
+        8 + // voting_ends_at
+        1 + 8 + // eta
+        8 + // for_votes
+        8 + // against_votes
+        1 + // status
+        1; // bump
+
+    /// Whether the proposal passed (majority and quorum)
+    pub fn passed(&self, quorum_votes: u64) -> bool {
+        let total = self.for_votes.saturating_add(self.against_votes);
+        self.for_votes > self.against_votes && total >= quorum_votes
+    }
+
+    /// Build the instruction to execute
+    pub fn instruction(&self) -> Instruction {
+        Instruction {
+            program_id: self.target_program,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|a| AccountMeta {
+                    pubkey: a.pubkey,
+                    is_signer: a.is_signer,
+                    is_writable: a.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+/// One wallet's vote on a proposal (also prevents double voting)
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // proposal
+        32 + // voter
+        1 + // support
+        8 + // weight
+        1; // bump
+}
@@ -38,4 +38,37 @@ pub enum JobError {
 
     #[msg("Job has not expired yet (cannot recover)")]
     JobNotExpired,
+
+    #[msg("Node xNOS is below market minimum")]
+    InsufficientStake,
+
+    #[msg("Assigned node account required")]
+    MissingNodeAccount,
+
+    #[msg("Node hardware descriptor required for jobs with requirements")]
+    MissingHardwareDescriptor,
+
+    #[msg("Node hardware does not meet job requirements")]
+    RequirementsNotMet,
+
+    #[msg("Invalid data residency (too many or invalid country codes)")]
+    InvalidResidency,
+
+    #[msg("Node location does not satisfy job data residency")]
+    ResidencyNotMet,
+
+    #[msg("Node is not waiting in the market queue")]
+    NodeNotInQueue,
+
+    #[msg("A queued job to claim is required while jobs are waiting")]
+    MissingJobAccount,
+
+    #[msg("Job is not queued in this market")]
+    JobNotQueued,
+
+    #[msg("Node has no valid audit")]
+    NodeNotAudited,
+
+    #[msg("Node takes delegations: its reward pool accounts are required")]
+    NodeRewardsRequired,
 }
@@ -1,5 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_nodes::cpi::accounts::RecordJobOutcome;
+use hypernode_nodes::program::HypernodeNodes;
+use hypernode_nodes::state::Node;
+use hypernode_rewards::cpi::accounts::AddNodeEarnings;
+use hypernode_rewards::program::HypernodeRewards;
+use hypernode_rewards::state::NodeRewardPool;
+use hypernode_staking::program::HypernodeStaking;
 use crate::state::*;
 use crate::errors::JobError;
 
@@ -8,8 +15,11 @@ use crate::errors::JobError;
 /// This implements trustless payment settlement:
 /// - Validates node authorization
 /// - Verifies IPFS result hash
-/// - Transfers payment from escrow to node
+/// - Transfers payment from escrow to node; nodes open for delegation are
+///   paid through their reward pool (hypernode-rewards CPI), which splits
+///   it into operator commission and delegator rewards
 /// - Updates job state and stats
+/// - Records outcome and earnings on the node (hypernode-nodes CPI)
 pub fn finish(
     ctx: Context<Finish>,
     ipfs_result: [u8; 32],
@@ -60,15 +70,48 @@ pub fn finish(
         ];
         let signer = &[&seeds[..]];
 
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.vault.to_account_info(),
-            to: ctx.accounts.node_token_account.to_account_info(),
-            authority: ctx.accounts.market.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        if ctx.accounts.node_delegation.data_is_empty() {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.node_token_account.to_account_info(),
+                authority: ctx.accounts.market.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
 
-        token::transfer(cpi_ctx, job.price)?;
+            token::transfer(cpi_ctx, job.price)?;
+        } else {
+            // Delegated node: commission to the operator, rest to delegators
+            let (
+                Some(node_reward_pool),
+                Some(node_rewards_vault),
+                Some(rewards_program),
+                Some(staking_program),
+            ) = (
+                ctx.accounts.node_reward_pool.as_ref(),
+                ctx.accounts.node_rewards_vault.as_ref(),
+                ctx.accounts.rewards_program.as_ref(),
+                ctx.accounts.staking_program.as_ref(),
+            ) else {
+                return err!(JobError::NodeRewardsRequired);
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(
+                rewards_program.to_account_info(),
+                AddNodeEarnings {
+                    node_reward_pool: node_reward_pool.to_account_info(),
+                    vault: node_rewards_vault.to_account_info(),
+                    node_delegation: ctx.accounts.node_delegation.to_account_info(),
+                    operator_token_account: ctx.accounts.node_token_account.to_account_info(),
+                    depositor_token_account: ctx.accounts.vault.to_account_info(),
+                    depositor: ctx.accounts.market.to_account_info(),
+                    staking_program: staking_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+                signer,
+            );
+            hypernode_rewards::cpi::add_node_earnings(cpi_ctx, job.price)?;
+        }
 
         msg!(
             "Job {} completed successfully. Paid {} to node {}",
@@ -123,6 +166,20 @@ pub fn finish(
         });
     }
 
+    // Record outcome on the node (jobs authority PDA signs)
+    let authority_seeds = &[b"jobs_authority".as_ref(), &[ctx.bumps.jobs_authority]];
+    let authority_signer = &[&authority_seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.nodes_program.to_account_info(),
+        RecordJobOutcome {
+            node: ctx.accounts.node.to_account_info(),
+            jobs_authority: ctx.accounts.jobs_authority.to_account_info(),
+        },
+        authority_signer,
+    );
+    let earnings = if success { job.price } else { 0 };
+    hypernode_nodes::cpi::record_job_outcome(cpi_ctx, success, earnings)?;
+
     Ok(())
 }
 
@@ -146,7 +203,50 @@ pub struct Finish<'info> {
     /// Node finishing the job
     pub node_authority: Signer<'info>,
 
-    /// Node's token account (payment destination)
+    /// Node account the job was assigned to (from hypernode-nodes program)
+    #[account(
+        mut,
+        constraint = Some(node.key()) == job.node_account @ JobError::UnauthorizedNode,
+        constraint = node.authority == node_authority.key() @ JobError::UnauthorizedNode
+    )]
+    pub node: Account<'info, Node>,
+
+    /// CHECK: Jobs authority PDA (signs CPIs into hypernode-nodes)
+    #[account(seeds = [b"jobs_authority"], bump)]
+    pub jobs_authority: UncheckedAccount<'info>,
+
+    /// Nodes program (records job outcomes)
+    pub nodes_program: Program<'info, HypernodeNodes>,
+
+    /// CHECK: Node's delegation PDA (hypernode-staking); if it exists, the
+    /// payment goes through the node's reward pool
+    #[account(
+        seeds = [b"delegation", node.key().as_ref()],
+        bump,
+        seeds::program = hypernode_staking::ID
+    )]
+    pub node_delegation: UncheckedAccount<'info>,
+
+    /// Node's reward pool (required if the node takes delegations)
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node.key().as_ref()],
+        bump = node_reward_pool.bump,
+        seeds::program = hypernode_rewards::ID
+    )]
+    pub node_reward_pool: Option<Account<'info, NodeRewardPool>>,
+
+    /// CHECK: Node reward pool vault (checked by hypernode-rewards)
+    #[account(mut)]
+    pub node_rewards_vault: Option<UncheckedAccount<'info>>,
+
+    /// Rewards program (required if the node takes delegations)
+    pub rewards_program: Option<Program<'info, HypernodeRewards>>,
+
+    /// Staking program (required if the node takes delegations)
+    pub staking_program: Option<Program<'info, HypernodeStaking>>,
+
+    /// Node's token account (payment or commission destination)
     #[account(
         mut,
         constraint = node_token_account.owner == node_authority.key()
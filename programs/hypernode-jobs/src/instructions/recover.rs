@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_nodes::cpi::accounts::RecordJobOutcome;
+use hypernode_nodes::program::HypernodeNodes;
+use hypernode_nodes::state::Node;
 use crate::state::*;
 use crate::errors::JobError;
 
@@ -12,6 +15,9 @@ use crate::errors::JobError;
 /// This prevents DoS attacks where:
 /// - Nodes claim jobs but never finish them
 /// - Jobs sit in queue forever
+///
+/// A timed-out running job counts as a failure against the assigned node
+/// (hypernode-nodes CPI, reputation), so `node` is required in that case.
 pub fn recover(ctx: Context<Recover>) -> Result<()> {
     let market = &ctx.accounts.market;
     let job = &mut ctx.accounts.job;
@@ -40,6 +46,8 @@ pub fn recover(ctx: Context<Recover>) -> Result<()> {
 
     require!(is_expired, JobError::JobNotExpired);
 
+    let was_running = job.state == JobState::Running;
+
     // Refund client
     let market_id = market.market_id.as_bytes();
     let seeds = &[b"market", market_id, &[market.bump]];
@@ -71,7 +79,7 @@ pub fn recover(ctx: Context<Recover>) -> Result<()> {
         job: job.key(),
         client: job.client,
         amount: job.price,
-        reason: if matches!(job.state, JobState::Running) {
+        reason: if was_running {
             "timeout_exceeded"
         } else {
             "stuck_in_queue"
@@ -80,6 +88,26 @@ pub fn recover(ctx: Context<Recover>) -> Result<()> {
         timestamp: clock.unix_timestamp,
     });
 
+    // Count the timeout against the assigned node (jobs authority PDA signs)
+    if was_running {
+        let node = ctx
+            .accounts
+            .node
+            .as_ref()
+            .ok_or(JobError::MissingNodeAccount)?;
+        let authority_seeds = &[b"jobs_authority".as_ref(), &[ctx.bumps.jobs_authority]];
+        let authority_signer = &[&authority_seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.nodes_program.to_account_info(),
+            RecordJobOutcome {
+                node: node.to_account_info(),
+                jobs_authority: ctx.accounts.jobs_authority.to_account_info(),
+            },
+            authority_signer,
+        );
+        hypernode_nodes::cpi::record_job_outcome(cpi_ctx, false, 0)?;
+    }
+
     Ok(())
 }
 
@@ -114,6 +142,20 @@ pub struct Recover<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    /// Assigned node (from hypernode-nodes program; required if running)
+    #[account(
+        mut,
+        constraint = Some(node.key()) == job.node_account @ JobError::UnauthorizedNode
+    )]
+    pub node: Option<Account<'info, Node>>,
+
+    /// CHECK: Jobs authority PDA (signs CPIs into hypernode-nodes)
+    #[account(seeds = [b"jobs_authority"], bump)]
+    pub jobs_authority: UncheckedAccount<'info>,
+
+    /// Nodes program (records job outcomes)
+    pub nodes_program: Program<'info, HypernodeNodes>,
+
     /// Anyone can call recover (permissionless)
     pub caller: Signer<'info>,
 
@@ -1,19 +1,31 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_nodes::cpi::accounts::RecordJobStarted;
+use hypernode_nodes::program::HypernodeNodes;
+use hypernode_nodes::state::{HardwareDescriptor, Node};
 use crate::state::*;
 use crate::errors::JobError;
 
 /// Submit a new job to the market
 ///
 /// This implements the Nosana-style dynamic queue system:
-/// - If nodes are waiting (QueueType::Node), assigns immediately
+/// - If nodes are waiting (QueueType::Node), assigns to `node`: any queued
+///   node satisfying the job's constraints, not only the queue head. It is
+///   marked as running the job via CPI to hypernode-nodes
+/// - If nodes are waiting but none matches, pass no `node`: the job waits
+///   as Queued until a matching node claims it with `work`
 /// - Otherwise, adds job to queue (QueueType::Job)
+///
+/// With hardware `requirements`, the node's `hardware` descriptor must meet
+/// them; with `residency`, its country/region must be allowed (e.g. EU-only);
+/// with `audited_only`, its audit must not have expired.
 pub fn submit_job(
     ctx: Context<SubmitJob>,
     job_id: String,
     ipfs_job: [u8; 32],
     price: u64,
     timeout: i64,
+    constraints: JobConstraints,
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let job = &mut ctx.accounts.job;
@@ -31,6 +43,9 @@ pub fn submit_job(
         JobError::QueueFull
     );
 
+    // Residency: valid ISO 3166-1 alpha-2 codes, within size limits
+    require!(constraints.is_valid(), JobError::InvalidResidency);
+
     // IPFS hash must not be empty (content-addressed storage)
     require!(
         ipfs_job != [0u8; 32],
@@ -55,35 +70,59 @@ pub fn submit_job(
     job.price = price;
     job.timeout = timeout;
     job.node = None;
+    job.node_account = None;
+    job.state = JobState::Queued;
     job.created_at = clock.unix_timestamp;
     job.started_at = None;
     job.completed_at = None;
     job.job_id = job_id;
+    job.constraints = constraints;
     job.bump = ctx.bumps.job;
 
     // Dynamic queue logic (Nosana pattern)
-    match market.queue_type {
-        QueueType::Node => {
+    match (&market.queue_type, &ctx.accounts.node) {
+        (QueueType::Node, Some(node)) => {
             // Node is waiting! Assign immediately (trustless matching)
-            require!(!market.queue.is_empty(), JobError::QueueEmpty);
-
-            let node_pubkey = market.queue.remove(0);
-            job.node = Some(node_pubkey);
-            job.state = JobState::Running;
-            job.started_at = Some(clock.unix_timestamp);
+            let position = market
+                .queue
+                .iter()
+                .position(|queued| *queued == node.key())
+                .ok_or(JobError::NodeNotInQueue)?;
+            job.constraints
+                .check(node, ctx.accounts.hardware.as_ref(), clock.unix_timestamp)?;
+
+            market.queue.remove(position);
+
+            // Mark the job as running on the node (jobs authority PDA signs)
+            let authority_seeds = &[b"jobs_authority".as_ref(), &[ctx.bumps.jobs_authority]];
+            let authority_signer = &[&authority_seeds[..]];
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.nodes_program.to_account_info(),
+                RecordJobStarted {
+                    node: node.to_account_info(),
+                    jobs_authority: ctx.accounts.jobs_authority.to_account_info(),
+                },
+                authority_signer,
+            );
+            hypernode_nodes::cpi::record_job_started(cpi_ctx)?;
+
+            job.start(node, clock.unix_timestamp);
 
             // Update queue type if empty
             if market.queue.is_empty() {
                 market.queue_type = QueueType::Empty;
             }
 
-            msg!("Job {} assigned immediately to node {}", job.job_id, node_pubkey);
+            msg!("Job {} assigned immediately to node {}", job.job_id, node.key());
+        }
+        (QueueType::Node, None) => {
+            // No waiting node matches: wait for a matching node's `work`
+            msg!("Job {} waiting for a matching node", job.job_id);
         }
         _ => {
             // No nodes available, enter job queue
             market.queue.push(job.key());
             market.queue_type = QueueType::Job;
-            job.state = JobState::Queued;
 
             msg!("Job {} added to queue (position {})", job.job_id, market.queue.len());
         }
@@ -134,6 +173,20 @@ pub struct SubmitJob<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    /// Queued node to assign the job to (None if no waiting node matches)
+    #[account(mut)]
+    pub node: Option<Account<'info, Node>>,
+
+    /// Hardware descriptor of that node (required if the job has requirements)
+    pub hardware: Option<Account<'info, HardwareDescriptor>>,
+
+    /// CHECK: Jobs authority PDA (signs CPIs into hypernode-nodes)
+    #[account(seeds = [b"jobs_authority"], bump)]
+    pub jobs_authority: UncheckedAccount<'info>,
+
+    /// Nodes program (tracks running jobs)
+    pub nodes_program: Program<'info, HypernodeNodes>,
+
     /// SPL Token program
     pub token_program: Program<'info, Token>,
 
@@ -1,72 +1,124 @@
 use anchor_lang::prelude::*;
+use hypernode_nodes::cpi::accounts::RecordJobStarted;
+use hypernode_nodes::program::HypernodeNodes;
+use hypernode_nodes::state::{HardwareDescriptor, Node};
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::{NodeDelegation, Staker};
 use crate::state::*;
 use crate::errors::JobError;
 
 /// Node enters work queue or claims available job
 ///
 /// Dynamic queue behavior:
-/// - If jobs are waiting (QueueType::Job), assigns immediately
-/// - Otherwise, adds node to queue (QueueType::Node)
+/// - With a `job`, claims it: any Queued job of the market whose
+///   constraints the node satisfies (hardware, residency), whether in the
+///   queue or waiting after no queued node matched it at submission
+/// - Otherwise, if jobs are waiting (QueueType::Job), a `job` is required
+/// - Otherwise, adds the node to the queue (QueueType::Node)
+///
+/// Market gating uses the operator's undelegated xNOS across all stake
+/// positions, plus xNOS delegated to the node (the operator's own included),
+/// plus HYPER carried over
+/// from the legacy registry (`Node.legacy_stake`, at the base 1x multiplier).
 pub fn work(ctx: Context<Work>) -> Result<()> {
     let market = &mut ctx.accounts.market;
-    let node_pubkey = ctx.accounts.node_authority.key();
+    let node = &ctx.accounts.node;
     let clock = Clock::get()?;
 
-    // Validation: queue not full
-    require!(
-        market.queue.len() < Market::MAX_QUEUE_SIZE,
-        JobError::QueueFull
-    );
-
-    // Validation: node not already in queue
+    // Validation: node meets market stake requirement (own + delegated + legacy)
+    let own_xnos = ctx
+        .accounts
+        .staker
+        .as_ref()
+        .map_or(0, |staker| staker.own_xnos());
+    let delegated_xnos = ctx
+        .accounts
+        .node_delegation
+        .as_ref()
+        .map_or(0, |delegation| delegation.delegated_xnos);
+    let node_xnos = own_xnos
+        .saturating_add(delegated_xnos)
+        .saturating_add(node.legacy_stake);
     require!(
-        !market.queue.contains(&node_pubkey),
-        JobError::NodeAlreadyInQueue
+        node_xnos >= market.node_stake_minimum,
+        JobError::InsufficientStake
     );
 
     // Update market stats
-    if market.total_nodes == 0 || !market.queue.contains(&node_pubkey) {
-        market.total_nodes = market.total_nodes.checked_add(1).unwrap();
-    }
-
-    // Dynamic queue logic (Nosana pattern - opposite of submit_job)
-    match market.queue_type {
-        QueueType::Job => {
-            // Job is waiting! Claim immediately (trustless matching)
-            require!(!market.queue.is_empty(), JobError::QueueEmpty);
+    market.total_nodes = market.total_nodes.checked_add(1).unwrap();
 
-            let job_pubkey = market.queue.remove(0);
-
-            // Note: The actual job assignment happens in the client
-            // The job account will be updated via a separate instruction
-            // This keeps the work() instruction simple and gas-efficient
+    match ctx.accounts.job.as_mut() {
+        Some(job) => {
+            // Claim a queued job the node satisfies (trustless matching)
+            require!(
+                job.market == market.key() && job.state == JobState::Queued,
+                JobError::JobNotQueued
+            );
+            job.constraints
+                .check(node, ctx.accounts.hardware.as_ref(), clock.unix_timestamp)?;
 
-            // Update queue type if empty
+            // Leave the queue: the job if it was queued, or the node if it
+            // was waiting for work
+            let job_key = job.key();
+            let node_key = node.key();
+            market
+                .queue
+                .retain(|queued| *queued != job_key && *queued != node_key);
             if market.queue.is_empty() {
                 market.queue_type = QueueType::Empty;
             }
 
+            // Mark the job as running on the node (jobs authority PDA signs)
+            let authority_seeds = &[b"jobs_authority".as_ref(), &[ctx.bumps.jobs_authority]];
+            let authority_signer = &[&authority_seeds[..]];
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.nodes_program.to_account_info(),
+                RecordJobStarted {
+                    node: node.to_account_info(),
+                    jobs_authority: ctx.accounts.jobs_authority.to_account_info(),
+                },
+                authority_signer,
+            );
+            hypernode_nodes::cpi::record_job_started(cpi_ctx)?;
+
+            job.start(node, clock.unix_timestamp);
+
             msg!(
                 "Node {} claimed job {} immediately",
-                node_pubkey,
-                job_pubkey
+                node.key(),
+                job.job_id
             );
 
             // Emit event for off-chain tracking
             emit!(JobAssignedEvent {
-                job: job_pubkey,
-                node: node_pubkey,
+                job: job.key(),
+                node: node.key(),
                 timestamp: clock.unix_timestamp,
             });
         }
-        _ => {
+        None => {
+            // Jobs are waiting: the node must claim one it satisfies
+            require!(
+                market.queue_type != QueueType::Job,
+                JobError::MissingJobAccount
+            );
+            require!(node.can_take_jobs(), JobError::NodeNotActive);
+            require!(
+                !market.queue.contains(&node.key()),
+                JobError::NodeAlreadyInQueue
+            );
+            require!(
+                market.queue.len() < Market::MAX_QUEUE_SIZE,
+                JobError::QueueFull
+            );
+
             // No jobs available, enter node queue
-            market.queue.push(node_pubkey);
+            market.queue.push(node.key());
             market.queue_type = QueueType::Node;
 
             msg!(
                 "Node {} entered queue (position {})",
-                node_pubkey,
+                node.key(),
                 market.queue.len()
             );
         }
@@ -85,10 +137,46 @@ pub struct Work<'info> {
     )]
     pub market: Account<'info, Market>,
 
-    /// Node authority (from hypernode-nodes program)
-    /// We don't load the full Node account here to save CU
-    /// Validation happens in hypernode-nodes program
+    /// Node operator
     pub node_authority: Signer<'info>,
+
+    /// Node account (from hypernode-nodes program); queued by its PDA key
+    #[account(
+        mut,
+        constraint = node.authority == node_authority.key() @ JobError::UnauthorizedNode
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Queued job to claim (required while jobs are waiting)
+    #[account(mut)]
+    pub job: Option<Account<'info, Job>>,
+
+    /// Node's hardware descriptor (required if the job has requirements)
+    pub hardware: Option<Account<'info, HardwareDescriptor>>,
+
+    /// Node's aggregate stake (optional, from hypernode-staking program)
+    #[account(
+        seeds = [b"staker", node_authority.key().as_ref()],
+        bump = staker.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staker: Option<Account<'info, Staker>>,
+
+    /// Stake delegated to the node (optional, from hypernode-staking program)
+    #[account(
+        constraint = node_delegation.node == node.key() @ JobError::UnauthorizedNode
+    )]
+    pub node_delegation: Option<Account<'info, NodeDelegation>>,
+
+    /// CHECK: Jobs authority PDA (signs CPIs into hypernode-nodes)
+    #[account(seeds = [b"jobs_authority"], bump)]
+    pub jobs_authority: UncheckedAccount<'info>,
+
+    /// Nodes program (tracks running jobs)
+    pub nodes_program: Program<'info, HypernodeNodes>,
+
+    /// Staking program (owner of staker accounts)
+    pub staking_program: Program<'info, HypernodeStaking>,
 }
 
 /// Event emitted when job is assigned to node
@@ -5,6 +5,7 @@ pub mod instructions;
 pub mod errors;
 
 use instructions::*;
+use state::JobConstraints;
 
 declare_id!("HYPRjobs11111111111111111111111111111111111");
 
@@ -50,9 +51,11 @@ pub mod hypernode_jobs {
     /// - ipfs_job: IPFS hash of job definition (content-addressed)
     /// - price: Payment amount (transferred to escrow)
     /// - timeout: Maximum execution time in seconds
+    /// - constraints: Optional hardware requirements and data residency
     ///
     /// Dynamic behavior:
-    /// - If nodes waiting → assigns immediately
+    /// - If nodes waiting → assigns to a queued node meeting the constraints
+    /// - If none matches → job waits for a matching node's `work`
     /// - Otherwise → adds to job queue
     pub fn submit_job(
         ctx: Context<SubmitJob>,
@@ -60,8 +63,9 @@ pub mod hypernode_jobs {
         ipfs_job: [u8; 32],
         price: u64,
         timeout: i64,
+        constraints: JobConstraints,
     ) -> Result<()> {
-        instructions::submit_job(ctx, job_id, ipfs_job, price, timeout)
+        instructions::submit_job(ctx, job_id, ipfs_job, price, timeout, constraints)
     }
 
     /// Node enters work queue or claims job
@@ -69,7 +73,8 @@ pub mod hypernode_jobs {
     /// Node calls this to indicate availability.
     ///
     /// Dynamic behavior:
-    /// - If jobs waiting → claims immediately
+    /// - With a queued job → claims it if the node meets its constraints
+    /// - If jobs waiting → a job to claim is required
     /// - Otherwise → enters node queue
     pub fn work(ctx: Context<Work>) -> Result<()> {
         instructions::work(ctx)
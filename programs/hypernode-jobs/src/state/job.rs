@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use hypernode_nodes::state::{DataResidency, HardwareDescriptor, HardwareRequirements, Node};
+use crate::errors::JobError;
 
 /// Job account with IPFS hashes
 #[account]
@@ -24,9 +26,12 @@ pub struct Job {
     /// Timeout (seconds)
     pub timeout: i64,
 
-    /// Assigned node (if any)
+    /// Assigned node's authority (if any)
     pub node: Option<Pubkey>,
 
+    /// Assigned Node PDA (hypernode-nodes) credited with the job's outcome
+    pub node_account: Option<Pubkey>,
+
     /// Timestamps
     pub created_at: i64,
     pub started_at: Option<i64>,
@@ -35,6 +40,9 @@ pub struct Job {
     /// Job identifier
     pub job_id: String,
 
+    /// What the assigned node must satisfy (hardware, location)
+    pub constraints: JobConstraints,
+
     /// PDA bump
     pub bump: u8,
 }
@@ -51,11 +59,83 @@ impl Job {
         1 + // state
         8 + // timeout
         1 + 32 + // node (Option<Pubkey>)
+        1 + 32 + // node_account (Option<Pubkey>)
         8 + // created_at
         1 + 8 + // started_at (Option<i64>)
         1 + 8 + // completed_at (Option<i64>)
         4 + Self::MAX_JOB_ID_LEN + // job_id
+        JobConstraints::SPACE + // constraints
         1; // bump
+
+    /// Assign the job to a node and start it
+    pub fn start(&mut self, node: &Account<Node>, now: i64) {
+        self.node = Some(node.authority);
+        self.node_account = Some(node.key());
+        self.state = JobState::Running;
+        self.started_at = Some(now);
+    }
+}
+
+/// Node constraints of a job, checked whenever it is matched to a node
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct JobConstraints {
+    /// Hardware the node must have (hypernode-nodes descriptor)
+    pub requirements: Option<HardwareRequirements>,
+
+    /// Countries/region the node must be in
+    pub residency: Option<DataResidency>,
+
+    /// Only nodes with an unexpired audit may run the job
+    pub audited_only: bool,
+}
+
+impl JobConstraints {
+    pub const SPACE: usize = 1 + HardwareRequirements::SPACE + // requirements
+        1 + DataResidency::SPACE + // residency
+        1; // audited_only
+
+    /// Within size limits and only valid country codes
+    pub fn is_valid(&self) -> bool {
+        self.residency.iter().all(DataResidency::is_valid)
+    }
+
+    /// Check a node (and its hardware descriptor, if the job has hardware
+    /// requirements) against the constraints at `now`
+    ///
+    /// The node must be able to take jobs (active, not stale or suspended).
+    pub fn check(
+        &self,
+        node: &Account<Node>,
+        hardware: Option<&Account<HardwareDescriptor>>,
+        now: i64,
+    ) -> Result<()> {
+        require!(node.can_take_jobs(), JobError::NodeNotActive);
+
+        if self.audited_only {
+            require!(node.is_audit_valid(now), JobError::NodeNotAudited);
+        }
+
+        if let Some(residency) = &self.residency {
+            require!(
+                residency.allows(&node.country, node.region),
+                JobError::ResidencyNotMet
+            );
+        }
+
+        if let Some(requirements) = &self.requirements {
+            let hardware = hardware.ok_or(JobError::MissingHardwareDescriptor)?;
+            require!(
+                hardware.node == node.key(),
+                JobError::MissingHardwareDescriptor
+            );
+            require!(
+                hardware.meets(requirements),
+                JobError::RequirementsNotMet
+            );
+        }
+
+        Ok(())
+    }
 }
 
 /// Job lifecycle states (simplified from Nosana)
@@ -15,7 +15,7 @@ pub struct Market {
     /// Market parameters
     pub job_price: u64,        // Base price in lamports
     pub job_timeout: i64,      // Default timeout in seconds
-    pub node_stake_minimum: u64, // Minimum stake required
+    pub node_stake_minimum: u64, // Minimum aggregate xNOS required
 
     /// Payment vault for this market
     pub vault: Pubkey,
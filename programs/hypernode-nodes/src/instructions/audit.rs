@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Set or revoke a node's audit status (registered auditors only)
+///
+/// - attestation_hash: Hash of the off-chain audit report
+/// - expires_at: Audit is ignored after this timestamp
+///
+/// Revoking a still-valid audit counts as a failed audit (reputation
+/// penalty); revoking an expired or absent audit only clears the fields.
+/// Audit status is also cleared when `update` changes hardware specs.
+pub fn audit(
+    ctx: Context<Audit>,
+    is_audited: bool,
+    attestation_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
+
+    if !is_audited {
+        let was_valid = node.is_audit_valid(clock.unix_timestamp);
+        node.clear_audit();
+        if was_valid {
+            node.audit_failures = node.audit_failures.saturating_add(1);
+            node.add_penalty(1, 0, clock.unix_timestamp);
+            node.refresh_reputation(clock.unix_timestamp);
+        }
+        msg!("Node {} audit revoked (failure counted: {})", node.node_id, was_valid);
+
+        emit!(NodeAuditRevokedEvent {
+            node: node.key(),
+            auditor: ctx.accounts.auditor.key(),
+            failure_counted: was_valid,
+            audit_failures: node.audit_failures,
+            timestamp: clock.unix_timestamp,
+        });
+        return Ok(());
+    }
+
+    require!(
+        expires_at > clock.unix_timestamp,
+        NodeError::InvalidAuditExpiry
+    );
+
+    node.is_audited = true;
+    node.audited_by = Some(ctx.accounts.auditor.key());
+    node.audit_attestation = attestation_hash;
+    node.audit_expires_at = expires_at;
+
+    msg!(
+        "Node {} audited by {} until {}",
+        node.node_id,
+        ctx.accounts.auditor.key(),
+        expires_at
+    );
+
+    emit!(NodeAuditedEvent {
+        node: node.key(),
+        auditor: ctx.accounts.auditor.key(),
+        attestation_hash,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Audit<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump,
+        constraint = config.is_auditor(&auditor.key()) @ NodeError::NotAuditor
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    pub auditor: Signer<'info>,
+}
+
+#[event]
+pub struct NodeAuditedEvent {
+    pub node: Pubkey,
+    pub auditor: Pubkey,
+    pub attestation_hash: [u8; 32],
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NodeAuditRevokedEvent {
+    pub node: Pubkey,
+    pub auditor: Pubkey,
+    pub failure_counted: bool,
+    pub audit_failures: u32,
+    pub timestamp: i64,
+}
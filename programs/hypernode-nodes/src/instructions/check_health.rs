@@ -7,42 +7,144 @@
  * - Enables automatic penalties for underperformance
  *
  * Architecture Principles:
- * - Trustless: Permissionless checks anyone can call
- * - Safe: Validates timestamp and prevents spam
+ * - Trustless: Staked checkers vote, a quorum decides
+ * - Safe: Signed, attributed votes; wrong checkers are slashable
  * - Clear: Simple pass/fail with timestamp
  */
 
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::instructions::register::NodeError;
 
 #[derive(Accounts)]
+#[instruction(round_id: u64)]
 pub struct CheckHealth<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
     #[account(
         mut,
-        seeds = [b"node", node.authority.as_ref()],
+        seeds = [b"node", node.node_id.as_bytes()],
         bump = node.bump
     )]
     pub node: Account<'info, Node>,
 
-    /// Checker authority (can be anyone - permissionless)
-    pub checker: Signer<'info>,
+    /// Votes for the node's current round (created by the first vote)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HealthRound::SPACE,
+        seeds = [b"health_round", node.key().as_ref(), &round_id.to_le_bytes()],
+        bump
+    )]
+    pub health_round: Account<'info, HealthRound>,
+
+    /// Registered, staked checker
+    #[account(
+        mut,
+        seeds = [b"checker", authority.key().as_ref()],
+        bump = checker.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub checker: Account<'info, Checker>,
+
+    /// Checker signing key
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
+/// Submit a signed health result for a node
+///
+/// Votes are collected per round. The node's stats and reputation only
+/// change once `quorum` distinct checkers agree within `window` seconds;
+/// rounds without quorum expire. The next round opens with the next vote.
+/// `round_id` is the node's `health_round`, or `health_round + 1` if the
+/// current round has expired.
 pub fn check_health(
     ctx: Context<CheckHealth>,
+    round_id: u64,
     passed: bool,
     message: String,
 ) -> Result<()> {
+    let params = ctx.accounts.config.checker_params;
     let node = &mut ctx.accounts.node;
+    let round = &mut ctx.accounts.health_round;
+    let checker = &mut ctx.accounts.checker;
     let clock = Clock::get()?;
 
-    // Prevent spam: minimum 5 minutes between checks
-    const MIN_CHECK_INTERVAL: i64 = 300; // 5 minutes
+    // Validation: eligible checker
     require!(
-        clock.unix_timestamp - node.last_health_check >= MIN_CHECK_INTERVAL,
-        ErrorCode::CheckTooFrequent
+        checker.is_eligible(params.min_stake),
+        NodeError::CheckerNotEligible
     );
 
+    // Expired round without quorum: move to the next one
+    if node.health_round_started_at != 0
+        && clock.unix_timestamp >= node.health_round_started_at + params.window
+    {
+        node.health_round = node.health_round.saturating_add(1);
+        node.health_round_started_at = 0;
+    }
+    require!(round_id == node.health_round, NodeError::InvalidHealthRound);
+
+    // Open a new round
+    if node.health_round_started_at == 0 {
+        // Prevent spam: minimum 5 minutes between rounds
+        const MIN_CHECK_INTERVAL: i64 = 300; // 5 minutes
+        require!(
+            clock.unix_timestamp - node.last_health_check >= MIN_CHECK_INTERVAL,
+            ErrorCode::CheckTooFrequent
+        );
+
+        round.node = node.key();
+        round.round_id = round_id;
+        round.started_at = clock.unix_timestamp;
+        round.votes = Vec::new();
+        round.outcome = None;
+        round.bump = ctx.bumps.health_round;
+        node.health_round_started_at = clock.unix_timestamp;
+    }
+
+    // Record the signed vote (one per checker)
+    require!(
+        !round.votes.iter().any(|v| v.checker == checker.authority),
+        NodeError::AlreadyVoted
+    );
+    round.votes.push(CheckerVote {
+        checker: checker.authority,
+        passed,
+        slashed: false,
+    });
+    checker.votes_submitted = checker.votes_submitted.saturating_add(1);
+
+    msg!(
+        "Checker {} voted {} on node {} (round {}): {}",
+        checker.authority,
+        if passed { "PASSED" } else { "FAILED" },
+        node.node_id,
+        round_id,
+        message
+    );
+
+    emit!(HealthVoteEvent {
+        node: node.key(),
+        round_id,
+        checker: checker.authority,
+        passed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Quorum reached: apply the outcome to the node
+    if round.count(passed) < params.quorum {
+        return Ok(());
+    }
+    round.outcome = Some(passed);
+
     // Update health check data
     node.last_health_check = clock.unix_timestamp;
     node.total_health_checks = node.total_health_checks.checked_add(1).unwrap();
@@ -51,10 +153,6 @@ pub fn check_health(
         node.passed_health_checks = node.passed_health_checks.checked_add(1).unwrap();
     } else {
         node.failed_health_checks = node.failed_health_checks.checked_add(1).unwrap();
-
-        // Reduce reputation on failed check
-        const REPUTATION_PENALTY: u16 = 10;
-        node.reputation_score = node.reputation_score.saturating_sub(REPUTATION_PENALTY);
     }
 
     // Calculate health check pass rate
@@ -62,11 +160,26 @@ pub fn check_health(
         node.health_check_pass_rate = ((node.passed_health_checks * 100) / node.total_health_checks) as u8;
     }
 
-    msg!("Health check result: {} - {}", if passed { "PASSED" } else { "FAILED" }, message);
+    node.refresh_reputation(clock.unix_timestamp);
+
+    // Close the round
+    node.health_round = node.health_round.saturating_add(1);
+    node.health_round_started_at = 0;
+
+    msg!("Health check result: {} (quorum of {})", if passed { "PASSED" } else { "FAILED" }, params.quorum);
 
     Ok(())
 }
 
+#[event]
+pub struct HealthVoteEvent {
+    pub node: Pubkey,
+    pub round_id: u64,
+    pub checker: Pubkey,
+    pub passed: bool,
+    pub timestamp: i64,
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Health check too frequent. Wait at least 5 minutes between checks.")]
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Close a deactivated node and return rent to the authority
+///
+/// Requires:
+/// - Node deactivated, with no running jobs
+/// - All GPUs deregistered (frees their device UUIDs)
+/// - Legacy stake unstaked and withdrawn (`unstake_legacy_stake`, then
+///   `withdraw_legacy_stake`)
+/// - No stake delegation account in hypernode-staking (close it there
+///   once all delegators have left)
+/// - No open compute challenge (resolved via `verify_challenge`, which
+///   returns its rent to the issuer)
+///
+/// The node's hardware descriptor, if any, is closed along with it.
+pub fn close_node(ctx: Context<CloseNode>) -> Result<()> {
+    let node = &ctx.accounts.node;
+
+    require!(node.deactivated, NodeError::NodeNotDeactivated);
+    require!(node.active_jobs == 0, NodeError::NodeHasActiveJobs);
+    require!(node.gpus.is_empty(), NodeError::NodeHasGpus);
+    require!(
+        node.legacy_stake == 0 && node.legacy_unstaking == 0,
+        NodeError::LegacyStakeNotWithdrawn
+    );
+    require!(
+        ctx.accounts.node_delegation.data_is_empty(),
+        NodeError::NodeHasDelegation
+    );
+    require!(
+        ctx.accounts.challenge.data_is_empty(),
+        NodeError::NodeHasOpenChallenge
+    );
+
+    // Close the hardware descriptor (rent back to the authority)
+    let hardware = ctx.accounts.hardware.to_account_info();
+    if !hardware.data_is_empty() && hardware.owner == &crate::ID {
+        let authority = ctx.accounts.authority.to_account_info();
+        **authority.lamports.borrow_mut() =
+            authority.lamports().checked_add(hardware.lamports()).unwrap();
+        **hardware.lamports.borrow_mut() = 0;
+        hardware.assign(&System::id());
+        hardware.realloc(0, false)?;
+    }
+
+    // Remove node from the operator index
+    let node_key = node.key();
+    let operator = &mut ctx.accounts.operator;
+    operator.nodes.retain(|key| key != &node_key);
+
+    msg!("Node {} closed", node.node_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseNode<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized,
+        close = authority
+    )]
+    pub node: Account<'info, Node>,
+
+    /// CHECK: Node's delegation PDA in hypernode-staking; must not exist
+    #[account(
+        seeds = [b"delegation", node.key().as_ref()],
+        bump,
+        seeds::program = staking_program::ID
+    )]
+    pub node_delegation: UncheckedAccount<'info>,
+
+    /// CHECK: Node's hardware descriptor PDA; closed if it exists
+    #[account(
+        mut,
+        seeds = [b"hardware", node.key().as_ref()],
+        bump
+    )]
+    pub hardware: UncheckedAccount<'info>,
+
+    /// CHECK: Node's challenge PDA; must not exist
+    #[account(
+        seeds = [b"challenge", node.key().as_ref()],
+        bump
+    )]
+    pub challenge: UncheckedAccount<'info>,
+
+    /// Index of the authority's nodes
+    #[account(
+        mut,
+        seeds = [b"operator", authority.key().as_ref()],
+        bump = operator.bump
+    )]
+    pub operator: Account<'info, Operator>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Take a node out of service (operator only)
+///
+/// Sets `is_active = false` until `reactivate`. Refused while the node
+/// has running jobs, so clients are never stranded.
+pub fn deactivate(ctx: Context<Deactivate>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+
+    require!(!node.deactivated, NodeError::NodeDeactivated);
+    require!(node.active_jobs == 0, NodeError::NodeHasActiveJobs);
+
+    node.is_active = false;
+    node.deactivated = true;
+
+    msg!("Node {} deactivated", node.node_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Deactivate<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    pub authority: Signer<'info>,
+}
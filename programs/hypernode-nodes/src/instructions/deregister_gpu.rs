@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Detach a GPU from a node and close its fingerprint (node operator only)
+///
+/// Frees the device UUID so the GPU can be registered under another node.
+/// Removing hardware clears the node's audit status.
+pub fn deregister_gpu(ctx: Context<DeregisterGpu>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let gpu_key = ctx.accounts.gpu.key();
+    let clock = Clock::get()?;
+
+    let index = node
+        .gpus
+        .iter()
+        .position(|key| key == &gpu_key)
+        .ok_or(NodeError::GpuNotFound)?;
+    node.gpus.remove(index);
+
+    if node.is_audit_valid(clock.unix_timestamp) {
+        node.clear_audit();
+        msg!("Hardware changed, audit status cleared");
+    }
+
+    msg!(
+        "GPU {} detached from node {} ({} GPUs)",
+        gpu_key,
+        node.node_id,
+        node.gpus.len()
+    );
+
+    emit!(GpuDeregisteredEvent {
+        node: node.key(),
+        gpu: gpu_key,
+        unique_device_id: ctx.accounts.gpu.unique_device_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeregisterGpu<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"gpu", gpu.unique_device_id.as_ref()],
+        bump = gpu.bump,
+        has_one = node @ NodeError::GpuNotFound,
+        close = authority
+    )]
+    pub gpu: Account<'info, GpuFingerprint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct GpuDeregisteredEvent {
+    pub node: Pubkey,
+    pub gpu: Pubkey,
+    pub unique_device_id: [u8; 32],
+    pub timestamp: i64,
+}
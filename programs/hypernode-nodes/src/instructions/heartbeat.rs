@@ -3,12 +3,22 @@ use crate::state::*;
 use crate::instructions::register::NodeError;
 
 /// Update node heartbeat to show it's still active
+///
+/// Marks the current hourly bucket as up and refreshes `uptime_percentage`
+/// over the 30-day window.
 pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
     let node = &mut ctx.accounts.node;
     let clock = Clock::get()?;
 
+    // Validation: operator-deactivated nodes must `reactivate` first
+    require!(!node.deactivated, NodeError::NodeDeactivated);
+
     node.last_heartbeat = clock.unix_timestamp;
-    node.is_active = true;
+    node.uptime.record(clock.unix_timestamp);
+    node.uptime_percentage = node.uptime.percentage();
+    node.refresh_reputation(clock.unix_timestamp);
+    // Nodes suspended by failed challenges stay inactive until they pass one
+    node.is_active = !node.is_challenge_suspended();
 
     Ok(())
 }
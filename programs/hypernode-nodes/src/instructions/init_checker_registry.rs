@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Set up the staked checker registry (admin only, once)
+///
+/// Creates the checker vault (PDA token account owned by the config)
+/// and pins the stake mint.
+pub fn init_checker_registry(
+    ctx: Context<InitCheckerRegistry>,
+    params: CheckerParams,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(params.is_valid(), NodeError::InvalidCheckerParams);
+
+    config.checker_mint = ctx.accounts.mint.key();
+    config.checker_vault = ctx.accounts.checker_vault.key();
+    config.checker_params = params;
+
+    msg!("Checker registry initialized");
+    msg!("Min stake: {}", params.min_stake);
+    msg!("Quorum: {} checkers within {} seconds", params.quorum, params.window);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitCheckerRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [b"nodes_config"],
+        bump = config.bump,
+        has_one = admin @ NodeError::NotAdmin
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    /// Vault holding checker stake (PDA, config is token authority)
+    #[account(
+        init,
+        payer = admin,
+        token::mint = mint,
+        token::authority = config,
+        seeds = [b"checker_vault"],
+        bump
+    )]
+    pub checker_vault: Account<'info, TokenAccount>,
+
+    /// HYPER mint
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+use crate::program::HypernodeNodes;
+
+/// Create the nodes configuration (admin = signer, no auditors)
+///
+/// Only the program's upgrade authority may initialize (and become admin),
+/// so the config PDA can't be claimed by whoever calls first after deploy.
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.auditors = Vec::new();
+    config.checker_mint = Pubkey::default();
+    config.checker_vault = Pubkey::default();
+    config.checker_params = CheckerParams::default();
+    config.stale_after = NodesConfig::DEFAULT_STALE_AFTER;
+    config.bump = ctx.bumps.config;
+
+    msg!("Nodes config initialized, admin {}", config.admin);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = NodesConfig::SPACE,
+        seeds = [b"nodes_config"],
+        bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    /// Program upgrade authority (becomes the nodes admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ NodeError::NotUpgradeAuthority
+    )]
+    pub program: Program<'info, HypernodeNodes>,
+
+    /// Upgrade data of this program (holds the upgrade authority)
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ NodeError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
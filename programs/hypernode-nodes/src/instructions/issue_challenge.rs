@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Issue a compute challenge to a node (staked checkers only)
+///
+/// - Nonce derived from the most recent slot hash (unpredictable)
+/// - Operation parameters within `ChallengeOp` bounds
+/// - Expected time derived from the node's declared gpu/cpu cores, at
+///   most `MAX_EXPECTED_TIME_MS`
+/// - At most one challenge per node every `CHALLENGE_COOLDOWN`
+/// - Node must answer with `respond_challenge` before the deadline (at
+///   least `MIN_RESPONSE_WINDOW` away)
+pub fn issue_challenge(ctx: Context<IssueChallenge>, operation: ChallengeOp) -> Result<()> {
+    let params = ctx.accounts.config.checker_params;
+    let node = &mut ctx.accounts.node;
+    let challenge = &mut ctx.accounts.challenge;
+    let clock = Clock::get()?;
+
+    // Validation: eligible checker, not the node's own operator
+    require!(
+        ctx.accounts.checker.is_eligible(params.min_stake),
+        NodeError::CheckerNotEligible
+    );
+    require!(
+        node.authority != ctx.accounts.authority.key(),
+        NodeError::CheckerNotEligible
+    );
+    require!(!node.deactivated, NodeError::NodeDeactivated);
+    require!(
+        clock.unix_timestamp
+            >= node
+                .last_challenge_at
+                .saturating_add(ComputeChallenge::CHALLENGE_COOLDOWN),
+        NodeError::ChallengeCooldown
+    );
+    require!(operation.is_valid(), NodeError::InvalidChallengeOp);
+
+    let expected_time_ms =
+        ComputeChallenge::expected_time_ms(&operation, node.gpu_cores, node.cpu_cores)
+            .ok_or(NodeError::ChallengeNotApplicable)?;
+    require!(
+        expected_time_ms <= ComputeChallenge::MAX_EXPECTED_TIME_MS,
+        NodeError::ChallengeTooLong
+    );
+
+    // Most recent slot hash: [len: u64][slot: u64][hash: 32 bytes]...
+    let slot_hashes = ctx.accounts.slot_hashes.try_borrow_data()?;
+    require!(slot_hashes.len() >= 48, NodeError::InvalidSlotHashes);
+    let recent_hash = &slot_hashes[16..48];
+
+    let nonce = hashv(&[
+        recent_hash,
+        node.key().as_ref(),
+        ctx.accounts.authority.key().as_ref(),
+        &clock.slot.to_le_bytes(),
+    ])
+    .to_bytes();
+
+    let tolerance_bps = ComputeChallenge::DEFAULT_TOLERANCE_BPS;
+
+    challenge.node = node.key();
+    challenge.issuer = ctx.accounts.authority.key();
+    challenge.nonce = nonce;
+    challenge.operation = operation;
+    challenge.expected_time_ms = expected_time_ms;
+    challenge.tolerance_bps = tolerance_bps;
+    challenge.issued_at = clock.unix_timestamp;
+    challenge.deadline_at =
+        ComputeChallenge::deadline(clock.unix_timestamp, expected_time_ms, tolerance_bps);
+    challenge.result_hash = None;
+    challenge.responded_at = None;
+    challenge.votes = Vec::new();
+    challenge.bump = ctx.bumps.challenge;
+
+    node.last_challenge_at = clock.unix_timestamp;
+
+    msg!(
+        "Challenge issued to node {}: {:?}, expected {} ms, deadline {}",
+        node.node_id,
+        operation,
+        expected_time_ms,
+        challenge.deadline_at
+    );
+
+    emit!(ChallengeIssuedEvent {
+        node: node.key(),
+        issuer: challenge.issuer,
+        nonce,
+        operation,
+        expected_time_ms,
+        deadline_at: challenge.deadline_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IssueChallenge<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Outstanding challenge for the node
+    #[account(
+        init,
+        payer = authority,
+        space = ComputeChallenge::SPACE,
+        seeds = [b"challenge", node.key().as_ref()],
+        bump
+    )]
+    pub challenge: Account<'info, ComputeChallenge>,
+
+    /// Registered, staked checker issuing the challenge
+    #[account(
+        seeds = [b"checker", authority.key().as_ref()],
+        bump = checker.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub checker: Account<'info, Checker>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar (nonce entropy)
+    #[account(address = slot_hashes::ID @ NodeError::InvalidSlotHashes)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ChallengeIssuedEvent {
+    pub node: Pubkey,
+    pub issuer: Pubkey,
+    pub nonce: [u8; 32],
+    pub operation: ChallengeOp,
+    pub expected_time_ms: u64,
+    pub deadline_at: i64,
+}
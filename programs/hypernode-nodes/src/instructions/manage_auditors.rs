@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Add an auditor (admin only)
+pub fn add_auditor(ctx: Context<ManageAuditors>, auditor: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(!config.is_auditor(&auditor), NodeError::AuditorExists);
+    require!(
+        config.auditors.len() < NodesConfig::MAX_AUDITORS,
+        NodeError::TooManyAuditors
+    );
+
+    config.auditors.push(auditor);
+
+    msg!("Auditor {} added", auditor);
+
+    Ok(())
+}
+
+/// Remove an auditor (admin only)
+///
+/// Existing audits stay valid until they expire.
+pub fn remove_auditor(ctx: Context<ManageAuditors>, auditor: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let index = config
+        .auditors
+        .iter()
+        .position(|key| key == &auditor)
+        .ok_or(NodeError::AuditorNotFound)?;
+    config.auditors.remove(index);
+
+    msg!("Auditor {} removed", auditor);
+
+    Ok(())
+}
+
+/// Hand the admin role to a new key (e.g. governance PDA)
+pub fn set_admin(ctx: Context<ManageAuditors>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.admin = new_admin;
+
+    msg!("Nodes admin set to {}", new_admin);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManageAuditors<'info> {
+    #[account(
+        mut,
+        seeds = [b"nodes_config"],
+        bump = config.bump,
+        has_one = admin @ NodeError::NotAdmin
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    pub admin: Signer<'info>,
+}
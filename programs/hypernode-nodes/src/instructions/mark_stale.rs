@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Mark a node inactive once its heartbeat is older than `stale_after`
+///
+/// Permissionless crank: anyone can call it. Also rolls the uptime window
+/// forward so missed buckets show up in `uptime_percentage`. The node is
+/// revived by its next `heartbeat`.
+pub fn mark_stale(ctx: Context<MarkStale>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
+
+    require!(node.is_active, NodeError::NodeNotActive);
+    require!(
+        clock.unix_timestamp - node.last_heartbeat > config.stale_after,
+        NodeError::NodeNotStale
+    );
+
+    node.is_active = false;
+    node.uptime.advance(clock.unix_timestamp);
+    node.uptime_percentage = node.uptime.percentage();
+    node.refresh_reputation(clock.unix_timestamp);
+
+    msg!(
+        "Node {} marked inactive (last heartbeat {}, uptime {}%)",
+        node.node_id,
+        node.last_heartbeat,
+        node.uptime_percentage
+    );
+
+    emit!(NodeStaleEvent {
+        node: node.key(),
+        last_heartbeat: node.last_heartbeat,
+        uptime_percentage: node.uptime_percentage,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MarkStale<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+}
+
+#[event]
+pub struct NodeStaleEvent {
+    pub node: Pubkey,
+    pub last_heartbeat: i64,
+    pub uptime_percentage: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use node_registry::cpi::accounts::MigrateOut;
+use node_registry::program::NodeRegistry;
+use node_registry::state::{NodeAccount, NodeStatus, RegistryConfig};
+use crate::state::*;
+use crate::instructions::register::{NodeError, NodeSpecs};
+
+/// Move a node from the legacy node-registry program into this registry
+///
+/// - Creates the equivalent `Node` under `node_id` and indexes it. Legacy
+///   ids were only unique per owner, so the operator picks the id here
+///   (like `register`). The legacy registry only stored a free-form
+///   `gpu_specs_hash` and `location`, so the operator supplies the specs
+/// - Carries over registration time, job counts and earnings
+/// - Closes the legacy `NodeAccount` via CPI and moves its escrowed stake
+///   into the legacy stake vault. Staked HYPER becomes `Node.legacy_stake`
+///   and keeps counting toward market stake until `unstake_legacy_stake`;
+///   HYPER that was already unstaking becomes `Node.legacy_unstaking` and
+///   keeps its legacy unlock time (see `withdraw_legacy_stake`)
+pub fn migrate_from_legacy(
+    ctx: Context<MigrateFromLegacy>,
+    node_id: String,
+    specs: NodeSpecs,
+) -> Result<()> {
+    let legacy = &ctx.accounts.legacy_node;
+    let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
+
+    let region = specs.validate()?;
+    require!(
+        node_id.len() <= Node::MAX_NODE_ID_LEN,
+        NodeError::NodeIdTooLong
+    );
+    require!(
+        legacy.status != NodeStatus::Suspended,
+        NodeError::LegacyNodeSuspended
+    );
+
+    let legacy_stake = legacy.stake_amount;
+    let legacy_unstaking = legacy.unstaking_amount;
+
+    node.authority = ctx.accounts.authority.key();
+    node.node_id = node_id;
+
+    // Hardware specs, location and endpoint
+    specs.apply(node, region);
+
+    // Carried-over stats
+    node.jobs_completed = legacy.jobs_completed;
+    node.jobs_failed = legacy.jobs_failed;
+    node.total_earned = legacy.total_earned;
+
+    node.version = 1;
+
+    // Timestamps (uptime tracking starts now)
+    node.registered_at = legacy.registered_at;
+    node.last_heartbeat = clock.unix_timestamp;
+    node.uptime = UptimeTracker::new(clock.unix_timestamp);
+    node.uptime.record(clock.unix_timestamp);
+    node.uptime_percentage = node.uptime.percentage();
+    node.is_active = legacy.status == NodeStatus::Online;
+    node.deactivated = false;
+    node.legacy_stake = legacy_stake;
+    node.legacy_unstaking = legacy_unstaking;
+    node.legacy_unstake_available_at = legacy.unstake_available_at;
+
+    // Audit, health and challenge stats start fresh (zeroed by init)
+    node.refresh_reputation(clock.unix_timestamp);
+    node.bump = ctx.bumps.node;
+
+    // Add node to the operator index
+    let node_key = node.key();
+    let operator = &mut ctx.accounts.operator;
+    Operator::ensure_capacity(
+        operator,
+        operator.nodes.len() + 1,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+    operator.authority = ctx.accounts.authority.key();
+    operator.nodes.push(node_key);
+    operator.bump = ctx.bumps.operator;
+
+    // Move legacy stake into the vault and close the legacy account
+    // (migration PDA signs)
+    let seeds = &[b"migration_authority".as_ref(), &[ctx.bumps.migration_authority]];
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.legacy_program.to_account_info(),
+        MigrateOut {
+            node_account: ctx.accounts.legacy_node.to_account_info(),
+            config: ctx.accounts.legacy_config.to_account_info(),
+            owner: ctx.accounts.authority.to_account_info(),
+            destination: ctx.accounts.legacy_stake_vault.to_account_info(),
+            stake_vault: ctx.accounts.registry_stake_vault.to_account_info(),
+            migration_authority: ctx.accounts.migration_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        signer,
+    );
+    node_registry::cpi::migrate_out(cpi_ctx)?;
+
+    msg!(
+        "Node {} migrated from legacy registry ({} jobs, {} earned, {} stake and {} unstaking carried over)",
+        node.node_id,
+        node.jobs_completed,
+        node.total_earned,
+        legacy_stake,
+        legacy_unstaking
+    );
+
+    emit!(NodeMigratedEvent {
+        node: node_key,
+        legacy_node: ctx.accounts.legacy_node.key(),
+        authority: node.authority,
+        legacy_stake,
+        legacy_unstaking,
+        total_earned: node.total_earned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(node_id: String)]
+pub struct MigrateFromLegacy<'info> {
+    /// Legacy node-registry account (closed by this instruction)
+    #[account(
+        mut,
+        constraint = legacy_node.owner == authority.key() @ NodeError::Unauthorized
+    )]
+    pub legacy_node: Account<'info, NodeAccount>,
+
+    /// Legacy registry config (stake vault authority)
+    pub legacy_config: Account<'info, RegistryConfig>,
+
+    /// Legacy registry's stake vault
+    /// CHECK: Validated by node-registry `migrate_out`
+    #[account(mut)]
+    pub registry_stake_vault: UncheckedAccount<'info>,
+
+    /// HYPER mint of the legacy registry
+    #[account(address = legacy_config.hyper_mint @ NodeError::InvalidMint)]
+    pub hyper_mint: Account<'info, Mint>,
+
+    /// Vault holding migrated stake of all nodes (migration PDA owns it)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"legacy_stake_vault"],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = migration_authority
+    )]
+    pub legacy_stake_vault: Account<'info, TokenAccount>,
+
+    /// Equivalent node in this registry
+    #[account(
+        init,
+        payer = authority,
+        space = Node::SPACE,
+        seeds = [b"node", node_id.as_bytes()],
+        bump
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Index of the authority's nodes
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Operator::space(0),
+        seeds = [b"operator", authority.key().as_ref()],
+        bump
+    )]
+    pub operator: Account<'info, Operator>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Migration PDA (signs the CPI into node-registry)
+    #[account(seeds = [b"migration_authority"], bump)]
+    pub migration_authority: UncheckedAccount<'info>,
+
+    pub legacy_program: Program<'info, NodeRegistry>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event]
+pub struct NodeMigratedEvent {
+    pub node: Pubkey,
+    pub legacy_node: Pubkey,
+    pub authority: Pubkey,
+    pub legacy_stake: u64,
+    pub legacy_unstaking: u64,
+    pub total_earned: u64,
+    pub timestamp: i64,
+}
@@ -2,8 +2,60 @@ pub mod register;
 pub mod update;
 pub mod heartbeat;
 pub mod check_health;
+pub mod deactivate;
+pub mod reactivate;
+pub mod close_node;
+pub mod initialize_config;
+pub mod manage_auditors;
+pub mod audit;
+pub mod init_checker_registry;
+pub mod set_checker_params;
+pub mod register_checker;
+pub mod unregister_checker;
+pub mod withdraw_checker_stake;
+pub mod slash_checker;
+pub mod issue_challenge;
+pub mod respond_challenge;
+pub mod verify_challenge;
+pub mod register_gpu;
+pub mod deregister_gpu;
+pub mod set_stale_after;
+pub mod mark_stale;
+pub mod record_job_outcome;
+pub mod record_job_started;
+pub mod migrate_from_legacy;
+pub mod unstake_legacy_stake;
+pub mod withdraw_legacy_stake;
+pub mod set_hardware;
+pub mod set_location;
 
 pub use register::*;
 pub use update::*;
 pub use heartbeat::*;
 pub use check_health::*;
+pub use deactivate::*;
+pub use reactivate::*;
+pub use close_node::*;
+pub use initialize_config::*;
+pub use manage_auditors::*;
+pub use audit::*;
+pub use init_checker_registry::*;
+pub use set_checker_params::*;
+pub use register_checker::*;
+pub use unregister_checker::*;
+pub use withdraw_checker_stake::*;
+pub use slash_checker::*;
+pub use issue_challenge::*;
+pub use respond_challenge::*;
+pub use verify_challenge::*;
+pub use register_gpu::*;
+pub use deregister_gpu::*;
+pub use set_stale_after::*;
+pub use mark_stale::*;
+pub use record_job_outcome::*;
+pub use record_job_started::*;
+pub use migrate_from_legacy::*;
+pub use unstake_legacy_stake::*;
+pub use withdraw_legacy_stake::*;
+pub use set_hardware::*;
+pub use set_location::*;
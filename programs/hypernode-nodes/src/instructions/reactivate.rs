@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Put a deactivated node back into service (operator only)
+///
+/// Counts as a heartbeat (uptime and reputation refreshed). Nodes suspended
+/// by failed challenges stay inactive until they pass one.
+pub fn reactivate(ctx: Context<Reactivate>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
+
+    require!(node.deactivated, NodeError::NodeNotDeactivated);
+
+    node.deactivated = false;
+    node.last_heartbeat = clock.unix_timestamp;
+    node.uptime.record(clock.unix_timestamp);
+    node.uptime_percentage = node.uptime.percentage();
+    node.refresh_reputation(clock.unix_timestamp);
+    node.is_active = !node.is_challenge_suspended();
+
+    msg!("Node {} reactivated", node.node_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Reactivate<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Record a finished job against the node and refresh its reputation
+///
+/// Only callable by hypernode-jobs via CPI: the jobs program's
+/// `[b"jobs_authority"]` PDA must sign. Called from `finish` (with the
+/// payment as `earnings` on success) and from `recover` on a running job
+/// (counted as a failure). Releases the job from `active_jobs`.
+pub fn record_job_outcome(
+    ctx: Context<RecordJobOutcome>,
+    success: bool,
+    earnings: u64,
+) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
+
+    if success {
+        node.jobs_completed = node.jobs_completed.saturating_add(1);
+        node.total_earned = node.total_earned.saturating_add(earnings);
+    } else {
+        node.jobs_failed = node.jobs_failed.saturating_add(1);
+    }
+    node.active_jobs = node.active_jobs.saturating_sub(1);
+
+    node.refresh_reputation(clock.unix_timestamp);
+
+    msg!(
+        "Node {} job {} (completed {}, failed {}, earned {}, reputation {})",
+        node.node_id,
+        if success { "completed" } else { "failed" },
+        node.jobs_completed,
+        node.jobs_failed,
+        node.total_earned,
+        node.reputation_score
+    );
+
+    emit!(JobOutcomeRecordedEvent {
+        node: node.key(),
+        success,
+        earnings: if success { earnings } else { 0 },
+        reputation_score: node.reputation_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordJobOutcome<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    /// hypernode-jobs authority PDA (signs via CPI)
+    #[account(
+        seeds = [b"jobs_authority"],
+        bump,
+        seeds::program = jobs_program::ID
+    )]
+    pub jobs_authority: Signer<'info>,
+}
+
+#[event]
+pub struct JobOutcomeRecordedEvent {
+    pub node: Pubkey,
+    pub success: bool,
+    pub earnings: u64,
+    pub reputation_score: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Count a job assigned to the node as running
+///
+/// Only callable by hypernode-jobs via CPI (`[b"jobs_authority"]` PDA
+/// signs) when a job is matched to the node. Stale or challenge-suspended
+/// nodes can't take jobs; nodes with running jobs can't be deactivated or
+/// closed.
+pub fn record_job_started(ctx: Context<RecordJobStarted>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+
+    require!(!node.deactivated, NodeError::NodeDeactivated);
+    require!(node.can_take_jobs(), NodeError::NodeNotActive);
+
+    node.active_jobs = node.active_jobs.saturating_add(1);
+
+    msg!("Node {} running {} jobs", node.node_id, node.active_jobs);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordJobStarted<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    /// hypernode-jobs authority PDA (signs via CPI)
+    #[account(
+        seeds = [b"jobs_authority"],
+        bump,
+        seeds::program = jobs_program::ID
+    )]
+    pub jobs_authority: Signer<'info>,
+}
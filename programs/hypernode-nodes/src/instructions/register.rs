@@ -1,65 +1,91 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 
+/// Hardware, location and endpoint of a node (register, migrate_from_legacy)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NodeSpecs {
+    pub architecture: Architecture,
+    /// ISO 3166-1 alpha-2 code; the node's region is derived from it
+    pub country: [u8; 2],
+    pub cpu_cores: u16,
+    pub gpu_cores: u16,
+    pub ram_gb: u16,
+    pub iops: u32,
+    pub storage_gb: u32,
+    pub endpoint: String,
+}
+
+impl NodeSpecs {
+    /// Check specs and derive the node's region from its country
+    pub fn validate(&self) -> Result<Region> {
+        // Validate all hardware specs are non-zero
+        require!(self.cpu_cores > 0, NodeError::InvalidHardwareSpec);
+        require!(self.ram_gb > 0, NodeError::InvalidHardwareSpec);
+        require!(self.storage_gb > 0, NodeError::InvalidHardwareSpec);
+        require!(
+            self.endpoint.len() <= Node::MAX_ENDPOINT_LEN,
+            NodeError::EndpointTooLong
+        );
+
+        region_of(&self.country).ok_or(error!(NodeError::InvalidCountryCode))
+    }
+
+    /// Write the specs and derived region to a node
+    pub fn apply(self, node: &mut Node, region: Region) {
+        node.architecture = self.architecture;
+        node.country = self.country;
+        node.region = region;
+        node.cpu_cores = self.cpu_cores;
+        node.gpu_cores = self.gpu_cores;
+        node.ram_gb = self.ram_gb;
+        node.iops = self.iops;
+        node.storage_gb = self.storage_gb;
+        node.gpus = Vec::new();
+        node.endpoint = self.endpoint;
+    }
+}
+
 /// Register a new node with hardware specifications
-pub fn register(
-    ctx: Context<Register>,
-    node_id: String,
-    architecture: Architecture,
-    country: Country,
-    cpu_cores: u16,
-    gpu_cores: u16,
-    ram_gb: u16,
-    iops: u32,
-    storage_gb: u32,
-    endpoint: String,
-) -> Result<()> {
+///
+/// Also adds the node to the authority's `Operator` index (created on the
+/// first registration), so one wallet can run a fleet of nodes.
+pub fn register(ctx: Context<Register>, node_id: String, specs: NodeSpecs) -> Result<()> {
     let node = &mut ctx.accounts.node;
     let clock = Clock::get()?;
 
-    // Validate all hardware specs are non-zero
-    require!(cpu_cores > 0, NodeError::InvalidHardwareSpec);
-    require!(ram_gb > 0, NodeError::InvalidHardwareSpec);
-    require!(storage_gb > 0, NodeError::InvalidHardwareSpec);
-
-    // Validate string lengths
+    let region = specs.validate()?;
     require!(
         node_id.len() <= Node::MAX_NODE_ID_LEN,
         NodeError::NodeIdTooLong
     );
-    require!(
-        endpoint.len() <= Node::MAX_ENDPOINT_LEN,
-        NodeError::EndpointTooLong
-    );
 
     node.authority = ctx.accounts.authority.key();
     node.node_id = node_id;
     node.is_audited = false;
+    node.audited_by = None;
+    node.audit_attestation = [0u8; 32];
+    node.audit_expires_at = 0;
+    node.audit_failures = 0;
 
-    // Hardware specs
-    node.architecture = architecture;
-    node.country = country;
-    node.cpu_cores = cpu_cores;
-    node.gpu_cores = gpu_cores;
-    node.ram_gb = ram_gb;
-    node.iops = iops;
-    node.storage_gb = storage_gb;
+    // Hardware specs, location and endpoint
+    specs.apply(node, region);
 
     // Initialize stats
     node.jobs_completed = 0;
     node.jobs_failed = 0;
     node.total_earned = 0;
-    node.reputation_score = 100; // Start at 100
-    node.uptime_percentage = 100; // Assume 100% initially
+    node.uptime = UptimeTracker::new(clock.unix_timestamp);
+    node.uptime.record(clock.unix_timestamp);
+    node.uptime_percentage = node.uptime.percentage();
 
-    // Network info
-    node.endpoint = endpoint;
     node.version = 1;
 
     // Timestamps
     node.registered_at = clock.unix_timestamp;
     node.last_heartbeat = clock.unix_timestamp;
     node.is_active = true;
+    node.deactivated = false;
+    node.active_jobs = 0;
 
     // Health check stats (initialized to zero)
     node.last_health_check = 0;
@@ -67,9 +93,34 @@ pub fn register(
     node.passed_health_checks = 0;
     node.failed_health_checks = 0;
     node.health_check_pass_rate = 0;
+    node.health_round = 0;
+    node.health_round_started_at = 0;
+    node.challenges_passed = 0;
+    node.challenge_failures = 0;
+    node.consecutive_challenge_failures = 0;
+    node.last_challenge_at = 0;
+    node.penalty_points = 0;
+    node.penalty_updated_at = 0;
+    node.legacy_stake = 0;
+
+    // Initial reputation from the shared formula
+    node.refresh_reputation(clock.unix_timestamp);
 
     node.bump = ctx.bumps.node;
 
+    // Add node to the operator index
+    let node_key = node.key();
+    let operator = &mut ctx.accounts.operator;
+    Operator::ensure_capacity(
+        operator,
+        operator.nodes.len() + 1,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+    operator.authority = ctx.accounts.authority.key();
+    operator.nodes.push(node_key);
+    operator.bump = ctx.bumps.operator;
+
     msg!(
         "Node registered: {} | {} cores CPU, {} cores GPU, {}GB RAM",
         node.node_id,
@@ -93,6 +144,16 @@ pub struct Register<'info> {
     )]
     pub node: Account<'info, Node>,
 
+    /// Index of the authority's nodes (grown on each registration)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Operator::space(0),
+        seeds = [b"operator", authority.key().as_ref()],
+        bump
+    )]
+    pub operator: Account<'info, Operator>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -115,4 +176,136 @@ pub enum NodeError {
 
     #[msg("Unauthorized")]
     Unauthorized,
+
+    #[msg("Node is deactivated")]
+    NodeDeactivated,
+
+    #[msg("Node must be deactivated first")]
+    NodeNotDeactivated,
+
+    #[msg("Node has running jobs")]
+    NodeHasActiveJobs,
+
+    #[msg("Node still has a stake delegation account")]
+    NodeHasDelegation,
+
+    #[msg("Only a registered auditor can audit nodes")]
+    NotAuditor,
+
+    #[msg("Only the nodes admin can do this")]
+    NotAdmin,
+
+    #[msg("Auditor list is full")]
+    TooManyAuditors,
+
+    #[msg("Auditor already registered")]
+    AuditorExists,
+
+    #[msg("Auditor not found")]
+    AuditorNotFound,
+
+    #[msg("Audit expiry must be in the future")]
+    InvalidAuditExpiry,
+
+    #[msg("Invalid checker parameters")]
+    InvalidCheckerParams,
+
+    #[msg("Checker registry not initialized")]
+    CheckersNotInitialized,
+
+    #[msg("Checker is not eligible (stake too low or exiting)")]
+    CheckerNotEligible,
+
+    #[msg("Checker already voted in this round")]
+    AlreadyVoted,
+
+    #[msg("Wrong health round for this node")]
+    InvalidHealthRound,
+
+    #[msg("Health round is not resolved")]
+    RoundNotResolved,
+
+    #[msg("Checker did not vote against the outcome, or was already slashed")]
+    NotSlashable,
+
+    #[msg("Checker has not requested exit")]
+    CheckerNotExiting,
+
+    #[msg("Checker unbonding not complete")]
+    CheckerUnbonding,
+
+    #[msg("Invalid token mint")]
+    InvalidMint,
+
+    #[msg("Node declares no cores for this challenge operation")]
+    ChallengeNotApplicable,
+
+    #[msg("Challenge already answered")]
+    ChallengeAnswered,
+
+    #[msg("Challenge response deadline passed")]
+    ChallengeExpired,
+
+    #[msg("Challenge still open for a response")]
+    ChallengePending,
+
+    #[msg("Invalid slot hashes sysvar")]
+    InvalidSlotHashes,
+
+    #[msg("Node already has the maximum number of GPUs")]
+    TooManyGpus,
+
+    #[msg("GPU fingerprint field exceeds maximum length")]
+    GpuFieldTooLong,
+
+    #[msg("GPU is not attached to this node")]
+    GpuNotFound,
+
+    #[msg("Node heartbeat is not stale yet")]
+    NodeNotStale,
+
+    #[msg("Staleness threshold must be positive")]
+    InvalidStaleAfter,
+
+    #[msg("Legacy node is suspended and cannot be migrated")]
+    LegacyNodeSuspended,
+
+    #[msg("Too many accelerator entries")]
+    TooManyAccelerators,
+
+    #[msg("Runtimes must be a non-empty set of supported runtimes")]
+    InvalidRuntimes,
+
+    #[msg("Country must be an ISO 3166-1 alpha-2 code")]
+    InvalidCountryCode,
+
+    #[msg("Node still has GPUs attached")]
+    NodeHasGpus,
+
+    #[msg("Challenge operation parameters out of bounds")]
+    InvalidChallengeOp,
+
+    #[msg("Challenge would take longer than the maximum expected time")]
+    ChallengeTooLong,
+
+    #[msg("The challenge issuer cannot verify its own challenge")]
+    IssuerCannotVerify,
+
+    #[msg("Node has no legacy stake")]
+    NoLegacyStake,
+
+    #[msg("Legacy stake must be withdrawn before closing the node")]
+    LegacyStakeNotWithdrawn,
+
+    #[msg("Node has an open compute challenge")]
+    NodeHasOpenChallenge,
+
+    #[msg("Only the program upgrade authority can initialize the config")]
+    NotUpgradeAuthority,
+
+    #[msg("Node was challenged too recently")]
+    ChallengeCooldown,
+
+    #[msg("Legacy stake is still in its unstake cooldown")]
+    LegacyStakeLocked,
 }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Register as a health checker by escrowing HYPER
+///
+/// `amount` must be at least the configured minimum checker stake.
+pub fn register_checker(ctx: Context<RegisterChecker>, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let checker = &mut ctx.accounts.checker;
+    let clock = Clock::get()?;
+
+    require!(
+        config.checker_vault != Pubkey::default(),
+        NodeError::CheckersNotInitialized
+    );
+    require!(
+        amount >= config.checker_params.min_stake,
+        NodeError::CheckerNotEligible
+    );
+
+    // Transfer stake to checker vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.checker_vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    checker.authority = ctx.accounts.authority.key();
+    checker.stake = amount;
+    checker.votes_submitted = 0;
+    checker.times_slashed = 0;
+    checker.exit_requested_at = None;
+    checker.registered_at = clock.unix_timestamp;
+    checker.bump = ctx.bumps.checker;
+
+    msg!("Checker {} registered with {} stake", checker.authority, amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterChecker<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Checker::SPACE,
+        seeds = [b"checker", authority.key().as_ref()],
+        bump
+    )]
+    pub checker: Account<'info, Checker>,
+
+    #[account(
+        mut,
+        address = config.checker_vault @ NodeError::CheckersNotInitialized
+    )]
+    pub checker_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key(),
+        constraint = authority_token_account.mint == config.checker_mint @ NodeError::InvalidMint
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Attach a GPU to a node by its fingerprint (node operator only)
+///
+/// The fingerprint PDA is seeded by `unique_device_id`, so registering a
+/// GPU already attached to any node fails. Attaching hardware clears the
+/// node's audit status.
+pub fn register_gpu(ctx: Context<RegisterGpu>, params: GpuFingerprintParams) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let gpu = &mut ctx.accounts.gpu;
+    let clock = Clock::get()?;
+
+    // Validation
+    require!(node.gpus.len() < Node::MAX_GPUS, NodeError::TooManyGpus);
+    require!(
+        params.cuda_version.len() <= GpuFingerprint::MAX_CUDA_VERSION_LEN
+            && params.driver_version.len() <= GpuFingerprint::MAX_DRIVER_VERSION_LEN
+            && params.compute_capability.len() <= GpuFingerprint::MAX_COMPUTE_CAPABILITY_LEN,
+        NodeError::GpuFieldTooLong
+    );
+    require!(
+        params.pcie_bandwidth > 0 && params.memory_bandwidth > 0,
+        NodeError::InvalidHardwareSpec
+    );
+
+    gpu.node = node.key();
+    gpu.authority = ctx.accounts.authority.key();
+    gpu.unique_device_id = params.unique_device_id;
+    gpu.cuda_version = params.cuda_version;
+    gpu.driver_version = params.driver_version;
+    gpu.pcie_bandwidth = params.pcie_bandwidth;
+    gpu.memory_bandwidth = params.memory_bandwidth;
+    gpu.compute_capability = params.compute_capability;
+    gpu.registered_at = clock.unix_timestamp;
+    gpu.bump = ctx.bumps.gpu;
+
+    node.gpus.push(gpu.key());
+
+    if node.is_audit_valid(clock.unix_timestamp) {
+        node.clear_audit();
+        msg!("Hardware changed, audit status cleared");
+    }
+
+    msg!(
+        "GPU {} attached to node {} ({} GPUs)",
+        gpu.key(),
+        node.node_id,
+        node.gpus.len()
+    );
+
+    emit!(GpuRegisteredEvent {
+        node: node.key(),
+        gpu: gpu.key(),
+        unique_device_id: gpu.unique_device_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: GpuFingerprintParams)]
+pub struct RegisterGpu<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Fingerprint PDA (fails if the device is already registered)
+    #[account(
+        init,
+        payer = authority,
+        space = GpuFingerprint::SPACE,
+        seeds = [b"gpu", params.unique_device_id.as_ref()],
+        bump
+    )]
+    pub gpu: Account<'info, GpuFingerprint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct GpuRegisteredEvent {
+    pub node: Pubkey,
+    pub gpu: Pubkey,
+    pub unique_device_id: [u8; 32],
+    pub timestamp: i64,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Answer a compute challenge with the result hash (node operator only)
+///
+/// Must arrive before the challenge deadline; a checker then verifies it.
+pub fn respond_challenge(ctx: Context<RespondChallenge>, result_hash: [u8; 32]) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let clock = Clock::get()?;
+
+    require!(challenge.result_hash.is_none(), NodeError::ChallengeAnswered);
+    require!(
+        clock.unix_timestamp <= challenge.deadline_at,
+        NodeError::ChallengeExpired
+    );
+
+    challenge.result_hash = Some(result_hash);
+    challenge.responded_at = Some(clock.unix_timestamp);
+
+    msg!(
+        "Node {} answered challenge after {} seconds",
+        ctx.accounts.node.node_id,
+        clock.unix_timestamp - challenge.issued_at
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RespondChallenge<'info> {
+    #[account(
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", node.key().as_ref()],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, ComputeChallenge>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Update checker quorum and staking parameters (admin only)
+pub fn set_checker_params(ctx: Context<SetCheckerParams>, params: CheckerParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        config.checker_vault != Pubkey::default(),
+        NodeError::CheckersNotInitialized
+    );
+    require!(params.is_valid(), NodeError::InvalidCheckerParams);
+
+    config.checker_params = params;
+
+    msg!("Checker params updated");
+    msg!("Min stake: {}", params.min_stake);
+    msg!("Quorum: {} checkers within {} seconds", params.quorum, params.window);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCheckerParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"nodes_config"],
+        bump = config.bump,
+        has_one = admin @ NodeError::NotAdmin
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Hardware descriptor contents submitted by the node operator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HardwareDescriptorParams {
+    pub accelerators: Vec<Accelerator>,
+    pub system_memory_mb: u64,
+    pub network_bandwidth_mbps: u32,
+    /// `Runtime` bit flags
+    pub runtimes: u8,
+}
+
+/// Create or replace a node's hardware descriptor (node operator only)
+///
+/// - Reallocates the descriptor when the accelerator list grows
+/// - Bumps `revision`; changing described hardware, or describing it for the
+///   first time, clears the node's audit
+pub fn set_hardware(ctx: Context<SetHardware>, params: HardwareDescriptorParams) -> Result<()> {
+    // Validation
+    require!(
+        params.accelerators.len() <= HardwareDescriptor::MAX_ACCELERATORS,
+        NodeError::TooManyAccelerators
+    );
+    require!(
+        params
+            .accelerators
+            .iter()
+            .all(|accelerator| accelerator.count > 0 && accelerator.vram_gb > 0),
+        NodeError::InvalidHardwareSpec
+    );
+    require!(params.system_memory_mb > 0, NodeError::InvalidHardwareSpec);
+    require!(
+        params.runtimes != 0 && params.runtimes & !Runtime::ALL == 0,
+        NodeError::InvalidRuntimes
+    );
+
+    HardwareDescriptor::ensure_capacity(
+        &ctx.accounts.hardware,
+        params.accelerators.len(),
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )?;
+
+    let node = &mut ctx.accounts.node;
+    let hardware = &mut ctx.accounts.hardware;
+    let clock = Clock::get()?;
+
+    // The first descriptor also counts: the audit predates it
+    let changed = !hardware.describes(
+        &params.accelerators,
+        params.system_memory_mb,
+        params.network_bandwidth_mbps,
+        params.runtimes,
+    );
+    if changed && node.is_audit_valid(clock.unix_timestamp) {
+        node.clear_audit();
+        msg!("Hardware changed, audit status cleared");
+    }
+
+    hardware.node = node.key();
+    hardware.version = HardwareDescriptor::CURRENT_VERSION;
+    hardware.revision = hardware.revision.saturating_add(1);
+    hardware.accelerators = params.accelerators;
+    hardware.system_memory_mb = params.system_memory_mb;
+    hardware.network_bandwidth_mbps = params.network_bandwidth_mbps;
+    hardware.runtimes = params.runtimes;
+    hardware.updated_at = clock.unix_timestamp;
+    hardware.bump = ctx.bumps.hardware;
+
+    msg!(
+        "Node {} hardware rev {}: {} accelerator models, {} GB VRAM, {} MB RAM",
+        node.node_id,
+        hardware.revision,
+        hardware.accelerators.len(),
+        hardware.total_vram_gb(),
+        hardware.system_memory_mb
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHardware<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Hardware descriptor (grown as accelerators are added)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HardwareDescriptor::space(0),
+        seeds = [b"hardware", node.key().as_ref()],
+        bump
+    )]
+    pub hardware: Account<'info, HardwareDescriptor>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
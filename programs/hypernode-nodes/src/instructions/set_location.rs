@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Update a node's country (node operator only)
+///
+/// - country: ISO 3166-1 alpha-2 code, validated on-chain
+/// - The node's region (continent, used by job data-residency constraints)
+///   is derived from the country, so the two can't disagree
+/// - Changing country clears the node's audit (it attested the old location)
+pub fn set_location(ctx: Context<SetLocation>, country: [u8; 2]) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+
+    let region = region_of(&country).ok_or(NodeError::InvalidCountryCode)?;
+
+    if node.country != country && node.is_audit_valid(Clock::get()?.unix_timestamp) {
+        node.clear_audit();
+        msg!("Country changed, audit status cleared");
+    }
+
+    node.country = country;
+    node.region = region;
+
+    msg!(
+        "Node {} location set to {}",
+        node.node_id,
+        String::from_utf8_lossy(&country)
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLocation<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::instructions::register::NodeError;
+use crate::instructions::manage_auditors::ManageAuditors;
+
+/// Set how long a node may go without a heartbeat before `mark_stale`
+/// can deactivate it (admin only)
+pub fn set_stale_after(ctx: Context<ManageAuditors>, stale_after: i64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(stale_after > 0, NodeError::InvalidStaleAfter);
+
+    config.stale_after = stale_after;
+
+    msg!("Nodes marked stale after {} seconds without heartbeat", stale_after);
+
+    Ok(())
+}
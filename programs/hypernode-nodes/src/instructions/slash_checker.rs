@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Slash a checker that voted against a resolved round (permissionless)
+///
+/// Burns `slash_bps` of the checker's stake. Each wrong vote is slashed once.
+pub fn slash_checker(ctx: Context<SlashChecker>, _round_id: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let round = &mut ctx.accounts.health_round;
+    let checker = &mut ctx.accounts.checker;
+
+    let outcome = round.outcome.ok_or(NodeError::RoundNotResolved)?;
+    let vote = round
+        .votes
+        .iter_mut()
+        .find(|v| v.checker == checker.authority && v.passed != outcome && !v.slashed)
+        .ok_or(NodeError::NotSlashable)?;
+    vote.slashed = true;
+
+    let slashed = (checker.stake as u128)
+        .checked_mul(config.checker_params.slash_bps as u128)
+        .map(|v| (v / 10000) as u64)
+        .unwrap_or(0);
+
+    if slashed > 0 {
+        let seeds = &[b"nodes_config".as_ref(), &[config.bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.checker_vault.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::burn(cpi_ctx, slashed)?;
+    }
+
+    checker.stake = checker.stake.saturating_sub(slashed);
+    checker.times_slashed = checker.times_slashed.saturating_add(1);
+
+    msg!(
+        "Checker {} slashed {} for wrong vote in round {} of node {}",
+        checker.authority,
+        slashed,
+        round.round_id,
+        round.node
+    );
+
+    emit!(CheckerSlashedEvent {
+        checker: checker.authority,
+        node: round.node,
+        round_id: round.round_id,
+        amount: slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SlashChecker<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"health_round", health_round.node.as_ref(), &round_id.to_le_bytes()],
+        bump = health_round.bump
+    )]
+    pub health_round: Account<'info, HealthRound>,
+
+    #[account(
+        mut,
+        seeds = [b"checker", checker.authority.as_ref()],
+        bump = checker.bump
+    )]
+    pub checker: Account<'info, Checker>,
+
+    #[account(
+        mut,
+        address = config.checker_vault @ NodeError::CheckersNotInitialized
+    )]
+    pub checker_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = config.checker_mint @ NodeError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct CheckerSlashedEvent {
+    pub checker: Pubkey,
+    pub node: Pubkey,
+    pub round_id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
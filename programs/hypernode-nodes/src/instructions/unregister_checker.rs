@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Stop checking and start the stake unbonding cooldown
+///
+/// The checker can no longer vote but stays slashable until withdrawal.
+pub fn unregister_checker(ctx: Context<UnregisterChecker>) -> Result<()> {
+    let checker = &mut ctx.accounts.checker;
+
+    require!(
+        checker.exit_requested_at.is_none(),
+        NodeError::CheckerNotEligible
+    );
+
+    checker.exit_requested_at = Some(Clock::get()?.unix_timestamp);
+
+    msg!("Checker {} exiting", checker.authority);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnregisterChecker<'info> {
+    #[account(
+        mut,
+        seeds = [b"checker", authority.key().as_ref()],
+        bump = checker.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub checker: Account<'info, Checker>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use node_registry::state::RegistryConfig;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Start unbonding stake carried over from the legacy registry (node
+/// operator only)
+///
+/// The stake backs the node while it is in service, so the node must be
+/// deactivated with no running jobs. `legacy_stake` moves to
+/// `legacy_unstaking`, stops counting toward market stake and becomes
+/// withdrawable after the legacy registry's `unstake_cooldown`.
+pub fn unstake_legacy_stake(ctx: Context<UnstakeLegacyStake>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let amount = node.legacy_stake;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, NodeError::NoLegacyStake);
+    require!(node.deactivated, NodeError::NodeNotDeactivated);
+    require!(node.active_jobs == 0, NodeError::NodeHasActiveJobs);
+
+    let available_at = clock
+        .unix_timestamp
+        .saturating_add(ctx.accounts.legacy_config.unstake_cooldown);
+
+    node.legacy_stake = 0;
+    node.legacy_unstaking = node.legacy_unstaking.saturating_add(amount);
+    node.legacy_unstake_available_at = node.legacy_unstake_available_at.max(available_at);
+
+    msg!(
+        "Node {} unstaking {} legacy stake, available at {}",
+        node.node_id,
+        amount,
+        node.legacy_unstake_available_at
+    );
+
+    emit!(LegacyStakeUnstakedEvent {
+        node: node.key(),
+        authority: node.authority,
+        amount,
+        available_at: node.legacy_unstake_available_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnstakeLegacyStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Legacy registry config (unstake cooldown)
+    #[account(
+        seeds = [b"registry_config"],
+        bump = legacy_config.bump,
+        seeds::program = node_registry::ID
+    )]
+    pub legacy_config: Account<'info, RegistryConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct LegacyStakeUnstakedEvent {
+    pub node: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub available_at: i64,
+    pub timestamp: i64,
+}
@@ -13,6 +13,7 @@ pub fn update(
     endpoint: String,
 ) -> Result<()> {
     let node = &mut ctx.accounts.node;
+    let clock = Clock::get()?;
 
     // Validate specs
     require!(cpu_cores > 0, NodeError::InvalidHardwareSpec);
@@ -23,6 +24,17 @@ pub fn update(
         NodeError::EndpointTooLong
     );
 
+    // Hardware changes invalidate the audit attestation
+    let specs_changed = node.cpu_cores != cpu_cores
+        || node.gpu_cores != gpu_cores
+        || node.ram_gb != ram_gb
+        || node.iops != iops
+        || node.storage_gb != storage_gb;
+    if specs_changed && node.is_audit_valid(clock.unix_timestamp) {
+        node.clear_audit();
+        msg!("Hardware changed, audit status cleared");
+    }
+
     // Update specs
     node.cpu_cores = cpu_cores;
     node.gpu_cores = gpu_cores;
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Vote on a challenge outcome (staked checkers other than the issuer)
+///
+/// - Answered: `valid` is the checker's off-chain recomputation result
+/// - Unanswered: once the deadline has passed, each vote confirms the
+///   missing answer (`valid` is ignored) and counts as a failure
+///
+/// The outcome applies once `quorum` distinct checkers agree; votes are
+/// accepted until `window` after the deadline, after which a call closes
+/// the challenge without effect. Failures cost reputation; after
+/// MAX_CONSECUTIVE_FAILURES the node is marked inactive until it passes a
+/// challenge. Once resolved or expired, the challenge is closed and rent
+/// returned to the issuer.
+pub fn verify_challenge(ctx: Context<VerifyChallenge>, valid: bool) -> Result<()> {
+    let params = ctx.accounts.config.checker_params;
+    let node = &mut ctx.accounts.node;
+    let challenge = &mut ctx.accounts.challenge;
+    let checker = &mut ctx.accounts.checker;
+    let clock = Clock::get()?;
+
+    // Validation: eligible checker, neither the node's operator nor the issuer
+    require!(
+        checker.is_eligible(params.min_stake),
+        NodeError::CheckerNotEligible
+    );
+    require!(
+        node.authority != checker.authority,
+        NodeError::CheckerNotEligible
+    );
+    require!(
+        challenge.issuer != checker.authority,
+        NodeError::IssuerCannotVerify
+    );
+
+    let valid = match challenge.result_hash {
+        Some(_) => valid,
+        None => {
+            require!(
+                clock.unix_timestamp > challenge.deadline_at,
+                NodeError::ChallengePending
+            );
+            false
+        }
+    };
+
+    // Verification window over without quorum: close without effect
+    if !challenge.is_voting_open(clock.unix_timestamp, params.window) {
+        msg!(
+            "Challenge for node {} expired without quorum ({} votes)",
+            node.node_id,
+            challenge.votes.len()
+        );
+        return ctx
+            .accounts
+            .challenge
+            .close(ctx.accounts.issuer.to_account_info());
+    }
+
+    // Record the signed vote (one per checker)
+    require!(
+        !challenge.votes.iter().any(|v| v.checker == checker.authority),
+        NodeError::AlreadyVoted
+    );
+    challenge.votes.push(CheckerVote {
+        checker: checker.authority,
+        passed: valid,
+        slashed: false,
+    });
+    checker.votes_submitted = checker.votes_submitted.saturating_add(1);
+
+    emit!(ChallengeVoteEvent {
+        node: node.key(),
+        checker: checker.authority,
+        valid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if challenge.count(valid) < params.quorum {
+        msg!(
+            "Checker {} voted {} on challenge for node {}",
+            checker.authority,
+            if valid { "VALID" } else { "INVALID" },
+            node.node_id
+        );
+        return Ok(());
+    }
+    let passed = valid;
+
+    if passed {
+        let was_suspended = node.is_challenge_suspended();
+        node.challenges_passed = node.challenges_passed.saturating_add(1);
+        node.consecutive_challenge_failures = 0;
+        if was_suspended && !node.deactivated {
+            node.is_active = true;
+        }
+    } else {
+        node.challenge_failures = node.challenge_failures.saturating_add(1);
+        node.consecutive_challenge_failures =
+            node.consecutive_challenge_failures.saturating_add(1);
+        node.add_penalty(0, 1, clock.unix_timestamp);
+        if node.is_challenge_suspended() {
+            node.is_active = false;
+        }
+    }
+
+    node.refresh_reputation(clock.unix_timestamp);
+
+    msg!(
+        "Challenge for node {} {} (resolved by {})",
+        node.node_id,
+        if passed { "PASSED" } else { "FAILED" },
+        checker.authority
+    );
+
+    emit!(ChallengeVerifiedEvent {
+        node: node.key(),
+        verifier: checker.authority,
+        passed,
+        answered: challenge.result_hash.is_some(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts
+        .challenge
+        .close(ctx.accounts.issuer.to_account_info())
+}
+
+#[derive(Accounts)]
+pub struct VerifyChallenge<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Closed to the issuer once resolved or expired
+    #[account(
+        mut,
+        seeds = [b"challenge", node.key().as_ref()],
+        bump = challenge.bump,
+        has_one = issuer
+    )]
+    pub challenge: Account<'info, ComputeChallenge>,
+
+    /// Challenge issuer (receives rent)
+    /// CHECK: Matched against challenge.issuer
+    #[account(mut)]
+    pub issuer: UncheckedAccount<'info>,
+
+    /// Registered, staked checker verifying the result
+    #[account(
+        mut,
+        seeds = [b"checker", authority.key().as_ref()],
+        bump = checker.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub checker: Account<'info, Checker>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct ChallengeVoteEvent {
+    pub node: Pubkey,
+    pub checker: Pubkey,
+    pub valid: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChallengeVerifiedEvent {
+    pub node: Pubkey,
+    pub verifier: Pubkey,
+    pub passed: bool,
+    pub answered: bool,
+    pub timestamp: i64,
+}
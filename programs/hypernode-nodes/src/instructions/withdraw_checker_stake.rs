@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Withdraw checker stake after the unbonding cooldown
+///
+/// Returns remaining (possibly slashed) stake and closes the checker.
+pub fn withdraw_checker_stake(ctx: Context<WithdrawCheckerStake>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let checker = &ctx.accounts.checker;
+    let clock = Clock::get()?;
+
+    let exit_requested_at = checker
+        .exit_requested_at
+        .ok_or(NodeError::CheckerNotExiting)?;
+    require!(
+        clock.unix_timestamp >= exit_requested_at + config.checker_params.unbonding,
+        NodeError::CheckerUnbonding
+    );
+
+    // Transfer stake from vault (config PDA is vault authority)
+    let seeds = &[b"nodes_config".as_ref(), &[config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.checker_vault.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, checker.stake)?;
+
+    msg!("Checker {} withdrew {} stake", checker.authority, checker.stake);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCheckerStake<'info> {
+    #[account(
+        seeds = [b"nodes_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, NodesConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"checker", authority.key().as_ref()],
+        bump = checker.bump,
+        has_one = authority @ NodeError::Unauthorized,
+        close = authority
+    )]
+    pub checker: Account<'info, Checker>,
+
+    #[account(
+        mut,
+        address = config.checker_vault @ NodeError::CheckersNotInitialized
+    )]
+    pub checker_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key(),
+        constraint = authority_token_account.mint == config.checker_mint @ NodeError::InvalidMint
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
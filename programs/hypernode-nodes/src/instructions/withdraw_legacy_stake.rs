@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::instructions::register::NodeError;
+
+/// Withdraw unbonded stake carried over from the legacy registry (node
+/// operator only)
+///
+/// Returns all of `legacy_unstaking` once `legacy_unstake_available_at` has
+/// passed: the legacy cooldown for HYPER that was unstaking at migration,
+/// or the one started by `unstake_legacy_stake`.
+pub fn withdraw_legacy_stake(ctx: Context<WithdrawLegacyStake>) -> Result<()> {
+    let node = &mut ctx.accounts.node;
+    let amount = node.legacy_unstaking;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, NodeError::NoLegacyStake);
+    require!(
+        clock.unix_timestamp >= node.legacy_unstake_available_at,
+        NodeError::LegacyStakeLocked
+    );
+
+    // Transfer stake from vault (migration PDA is vault authority)
+    let seeds = &[b"migration_authority".as_ref(), &[ctx.bumps.migration_authority]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.legacy_stake_vault.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: ctx.accounts.migration_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    node.legacy_unstaking = 0;
+
+    msg!("Node {} withdrew {} legacy stake", node.node_id, amount);
+
+    emit!(LegacyStakeWithdrawnEvent {
+        node: node.key(),
+        authority: node.authority,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLegacyStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"node", node.node_id.as_bytes()],
+        bump = node.bump,
+        has_one = authority @ NodeError::Unauthorized
+    )]
+    pub node: Account<'info, Node>,
+
+    #[account(
+        mut,
+        seeds = [b"legacy_stake_vault"],
+        bump
+    )]
+    pub legacy_stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key(),
+        constraint = authority_token_account.mint == legacy_stake_vault.mint @ NodeError::InvalidMint
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Migration PDA (legacy stake vault authority)
+    #[account(seeds = [b"migration_authority"], bump)]
+    pub migration_authority: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct LegacyStakeWithdrawnEvent {
+    pub node: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -13,30 +13,10 @@ pub mod hypernode_nodes {
     use super::*;
 
     /// Register a new node with hardware specifications
-    pub fn register(
-        ctx: Context<Register>,
-        node_id: String,
-        architecture: Architecture,
-        country: Country,
-        cpu_cores: u16,
-        gpu_cores: u16,
-        ram_gb: u16,
-        iops: u32,
-        storage_gb: u32,
-        endpoint: String,
-    ) -> Result<()> {
-        instructions::register::register(
-            ctx,
-            node_id,
-            architecture,
-            country,
-            cpu_cores,
-            gpu_cores,
-            ram_gb,
-            iops,
-            storage_gb,
-            endpoint,
-        )
+    ///
+    /// The node's region is derived from its ISO 3166-1 country code.
+    pub fn register(ctx: Context<Register>, node_id: String, specs: NodeSpecs) -> Result<()> {
+        instructions::register::register(ctx, node_id, specs)
     }
 
     /// Update node hardware specifications
@@ -65,12 +45,175 @@ pub mod hypernode_nodes {
         instructions::heartbeat::heartbeat(ctx)
     }
 
-    /// Check node health (permissionless - Checker system)
+    /// Submit a signed health vote (staked checkers only)
+    ///
+    /// Node stats move once a quorum of distinct checkers agrees.
     pub fn check_health(
         ctx: Context<CheckHealth>,
+        round_id: u64,
         passed: bool,
         message: String,
     ) -> Result<()> {
-        instructions::check_health::check_health(ctx, passed, message)
+        instructions::check_health::check_health(ctx, round_id, passed, message)
+    }
+
+    /// Take node out of service (refused while jobs are running)
+    pub fn deactivate(ctx: Context<Deactivate>) -> Result<()> {
+        instructions::deactivate::deactivate(ctx)
+    }
+
+    /// Put a deactivated node back into service
+    pub fn reactivate(ctx: Context<Reactivate>) -> Result<()> {
+        instructions::reactivate::reactivate(ctx)
+    }
+
+    /// Close a deactivated node and reclaim rent
+    pub fn close_node(ctx: Context<CloseNode>) -> Result<()> {
+        instructions::close_node::close_node(ctx)
+    }
+
+    /// Create the nodes config (admin + auditor registry)
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx)
+    }
+
+    /// Register an auditor (admin only)
+    pub fn add_auditor(ctx: Context<ManageAuditors>, auditor: Pubkey) -> Result<()> {
+        instructions::manage_auditors::add_auditor(ctx, auditor)
+    }
+
+    /// Remove an auditor (admin only)
+    pub fn remove_auditor(ctx: Context<ManageAuditors>, auditor: Pubkey) -> Result<()> {
+        instructions::manage_auditors::remove_auditor(ctx, auditor)
+    }
+
+    /// Transfer the admin role (admin only)
+    pub fn set_admin(ctx: Context<ManageAuditors>, new_admin: Pubkey) -> Result<()> {
+        instructions::manage_auditors::set_admin(ctx, new_admin)
+    }
+
+    /// Set or revoke node audit status with attestation and expiry (auditors only)
+    pub fn audit(
+        ctx: Context<Audit>,
+        is_audited: bool,
+        attestation_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::audit::audit(ctx, is_audited, attestation_hash, expires_at)
+    }
+
+    /// Create the checker stake vault and set quorum params (admin only)
+    pub fn init_checker_registry(
+        ctx: Context<InitCheckerRegistry>,
+        params: CheckerParams,
+    ) -> Result<()> {
+        instructions::init_checker_registry::init_checker_registry(ctx, params)
+    }
+
+    /// Update checker quorum and staking params (admin only)
+    pub fn set_checker_params(ctx: Context<SetCheckerParams>, params: CheckerParams) -> Result<()> {
+        instructions::set_checker_params::set_checker_params(ctx, params)
+    }
+
+    /// Become a health checker by escrowing HYPER
+    pub fn register_checker(ctx: Context<RegisterChecker>, amount: u64) -> Result<()> {
+        instructions::register_checker::register_checker(ctx, amount)
+    }
+
+    /// Stop checking and start the stake cooldown
+    pub fn unregister_checker(ctx: Context<UnregisterChecker>) -> Result<()> {
+        instructions::unregister_checker::unregister_checker(ctx)
+    }
+
+    /// Withdraw checker stake after the cooldown
+    pub fn withdraw_checker_stake(ctx: Context<WithdrawCheckerStake>) -> Result<()> {
+        instructions::withdraw_checker_stake::withdraw_checker_stake(ctx)
+    }
+
+    /// Slash a checker that voted against a resolved round (permissionless)
+    pub fn slash_checker(ctx: Context<SlashChecker>, round_id: u64) -> Result<()> {
+        instructions::slash_checker::slash_checker(ctx, round_id)
+    }
+
+    /// Issue a compute challenge to a node (staked checkers only)
+    pub fn issue_challenge(ctx: Context<IssueChallenge>, operation: ChallengeOp) -> Result<()> {
+        instructions::issue_challenge::issue_challenge(ctx, operation)
+    }
+
+    /// Answer a compute challenge before its deadline (node operator)
+    pub fn respond_challenge(ctx: Context<RespondChallenge>, result_hash: [u8; 32]) -> Result<()> {
+        instructions::respond_challenge::respond_challenge(ctx, result_hash)
+    }
+
+    /// Confirm a challenge outcome; failures cost reputation (staked checkers)
+    pub fn verify_challenge(ctx: Context<VerifyChallenge>, valid: bool) -> Result<()> {
+        instructions::verify_challenge::verify_challenge(ctx, valid)
+    }
+
+    /// Attach a GPU by fingerprint; device UUIDs are unique network-wide
+    pub fn register_gpu(ctx: Context<RegisterGpu>, params: GpuFingerprintParams) -> Result<()> {
+        instructions::register_gpu::register_gpu(ctx, params)
+    }
+
+    /// Detach a GPU and free its device UUID
+    pub fn deregister_gpu(ctx: Context<DeregisterGpu>) -> Result<()> {
+        instructions::deregister_gpu::deregister_gpu(ctx)
+    }
+
+    /// Set the heartbeat staleness threshold (admin only)
+    pub fn set_stale_after(ctx: Context<ManageAuditors>, stale_after: i64) -> Result<()> {
+        instructions::set_stale_after::set_stale_after(ctx, stale_after)
+    }
+
+    /// Mark a node with a stale heartbeat inactive (permissionless crank)
+    pub fn mark_stale(ctx: Context<MarkStale>) -> Result<()> {
+        instructions::mark_stale::mark_stale(ctx)
+    }
+
+    /// Count a newly assigned job as running (hypernode-jobs CPI only)
+    pub fn record_job_started(ctx: Context<RecordJobStarted>) -> Result<()> {
+        instructions::record_job_started::record_job_started(ctx)
+    }
+
+    /// Record job outcome and earnings, refresh reputation (hypernode-jobs CPI only)
+    pub fn record_job_outcome(
+        ctx: Context<RecordJobOutcome>,
+        success: bool,
+        earnings: u64,
+    ) -> Result<()> {
+        instructions::record_job_outcome::record_job_outcome(ctx, success, earnings)
+    }
+
+    /// Migrate a node from the legacy node-registry program
+    pub fn migrate_from_legacy(
+        ctx: Context<MigrateFromLegacy>,
+        node_id: String,
+        specs: NodeSpecs,
+    ) -> Result<()> {
+        instructions::migrate_from_legacy::migrate_from_legacy(ctx, node_id, specs)
+    }
+
+    /// Start unbonding stake carried over from the legacy registry
+    /// (deactivated node)
+    pub fn unstake_legacy_stake(ctx: Context<UnstakeLegacyStake>) -> Result<()> {
+        instructions::unstake_legacy_stake::unstake_legacy_stake(ctx)
+    }
+
+    /// Withdraw legacy stake once its unstake cooldown has passed
+    pub fn withdraw_legacy_stake(ctx: Context<WithdrawLegacyStake>) -> Result<()> {
+        instructions::withdraw_legacy_stake::withdraw_legacy_stake(ctx)
+    }
+
+    /// Create or replace the node's reallocatable hardware descriptor
+    pub fn set_hardware(
+        ctx: Context<SetHardware>,
+        params: HardwareDescriptorParams,
+    ) -> Result<()> {
+        instructions::set_hardware::set_hardware(ctx, params)
+    }
+
+    /// Update node country (ISO 3166-1 alpha-2); region is derived from it
+    pub fn set_location(ctx: Context<SetLocation>, country: [u8; 2]) -> Result<()> {
+        instructions::set_location::set_location(ctx, country)
     }
 }
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::state::{CheckerVote, HealthRound};
+
+/// Compute operation a node must run for a challenge
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChallengeOp {
+    MatrixMultiply { size: u32 },
+    Fft { size: u32 },
+    Sha256Hash { iterations: u64 },
+    RandomInference { model_hash: [u8; 32] },
+}
+
+impl ChallengeOp {
+    /// Matrix dimension bounds
+    pub const MIN_MATRIX_SIZE: u32 = 256;
+    pub const MAX_MATRIX_SIZE: u32 = 8192;
+
+    /// FFT length bounds (powers of two)
+    pub const MIN_FFT_SIZE: u32 = 1 << 10;
+    pub const MAX_FFT_SIZE: u32 = 1 << 24;
+
+    /// SHA-256 iteration bounds
+    pub const MIN_SHA256_ITERATIONS: u64 = 1_000_000;
+    pub const MAX_SHA256_ITERATIONS: u64 = 100_000_000;
+
+    /// Parameters within the bounds above
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            ChallengeOp::MatrixMultiply { size } => {
+                (Self::MIN_MATRIX_SIZE..=Self::MAX_MATRIX_SIZE).contains(&size)
+            }
+            ChallengeOp::Fft { size } => {
+                size.is_power_of_two()
+                    && (Self::MIN_FFT_SIZE..=Self::MAX_FFT_SIZE).contains(&size)
+            }
+            ChallengeOp::Sha256Hash { iterations } => {
+                (Self::MIN_SHA256_ITERATIONS..=Self::MAX_SHA256_ITERATIONS).contains(&iterations)
+            }
+            ChallengeOp::RandomInference { model_hash } => model_hash != [0u8; 32],
+        }
+    }
+
+    /// Abstract work units (1 unit = 1M basic operations)
+    pub fn work_units(&self) -> u64 {
+        const MILLION: u64 = 1_000_000;
+        match *self {
+            ChallengeOp::MatrixMultiply { size } => {
+                let n = size as u64;
+                n.saturating_mul(n).saturating_mul(n) / MILLION
+            }
+            ChallengeOp::Fft { size } => {
+                let n = size.max(2) as u64;
+                let log2 = 64 - (n - 1).leading_zeros() as u64;
+                n.saturating_mul(log2).saturating_mul(5) / MILLION
+            }
+            ChallengeOp::Sha256Hash { iterations } => iterations.saturating_mul(64) / MILLION,
+            ChallengeOp::RandomInference { .. } => 2_000_000,
+        }
+    }
+
+    /// Whether the operation runs on GPUs (otherwise CPU-bound)
+    pub fn is_gpu(&self) -> bool {
+        !matches!(self, ChallengeOp::Sha256Hash { .. })
+    }
+}
+
+/// Challenge-response proof that a node has the hardware it declares
+///
+/// PDA `[b"challenge", node]` (one outstanding challenge per node).
+/// Issued by a staked checker with a nonce from recent slot hashes; the
+/// node answers with the result hash before the deadline; other checkers
+/// recompute it off-chain and vote on-chain until `quorum` agree.
+#[account]
+pub struct ComputeChallenge {
+    pub node: Pubkey,
+
+    /// Checker that issued the challenge (receives rent on close)
+    pub issuer: Pubkey,
+
+    /// Random seed for the operation inputs
+    pub nonce: [u8; 32],
+
+    pub operation: ChallengeOp,
+
+    /// Expected run time for the node's declared cpu/gpu cores
+    pub expected_time_ms: u64,
+
+    /// Allowed variance over expected time (basis points)
+    pub tolerance_bps: u16,
+
+    pub issued_at: i64,
+
+    /// Response must arrive before this timestamp
+    pub deadline_at: i64,
+
+    /// Node's answer
+    pub result_hash: Option<[u8; 32]>,
+    pub responded_at: Option<i64>,
+
+    /// Verdicts on the answer (never from the issuer)
+    pub votes: Vec<CheckerVote>,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ComputeChallenge {
+    /// Default variance allowed over expected time: 20%
+    pub const DEFAULT_TOLERANCE_BPS: u16 = 2000;
+
+    /// Extra seconds for transaction latency
+    pub const RESPONSE_GRACE: i64 = 10;
+
+    /// Shortest time a node gets to answer, however small the operation
+    pub const MIN_RESPONSE_WINDOW: i64 = 60;
+
+    /// Minimum time between two challenges to the same node: 1 hour
+    pub const CHALLENGE_COOLDOWN: i64 = 60 * 60;
+
+    /// Throughput per core (work units per second)
+    pub const GPU_CORE_UNITS_PER_SEC: u64 = 10;
+    pub const CPU_CORE_UNITS_PER_SEC: u64 = 1;
+
+    /// Consecutive failures after which the node is marked inactive
+    pub const MAX_CONSECUTIVE_FAILURES: u8 = 3;
+
+    /// Longest expected run time a challenge may demand: 10 minutes
+    pub const MAX_EXPECTED_TIME_MS: u64 = 600_000;
+
+    /// Same vote capacity as a health round (always decides)
+    pub const MAX_VOTES: usize = HealthRound::MAX_VOTES;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // node
+        32 + // issuer
+        32 + // nonce
+        1 + 32 + // operation (largest variant)
+        8 + // expected_time_ms
+        2 + // tolerance_bps
+        8 + // issued_at
+        8 + // deadline_at
+        1 + 32 + // result_hash
+        1 + 8 + // responded_at
+        4 + Self::MAX_VOTES * CheckerVote::SPACE + // votes
+        1; // bump
+
+    /// Expected run time for an operation on declared hardware
+    ///
+    /// None if the node declares no cores of the required kind.
+    pub fn expected_time_ms(op: &ChallengeOp, gpu_cores: u16, cpu_cores: u16) -> Option<u64> {
+        let units_per_sec = if op.is_gpu() {
+            (gpu_cores as u64).saturating_mul(Self::GPU_CORE_UNITS_PER_SEC)
+        } else {
+            (cpu_cores as u64).saturating_mul(Self::CPU_CORE_UNITS_PER_SEC)
+        };
+        if units_per_sec == 0 {
+            return None;
+        }
+
+        Some(op.work_units().saturating_mul(1000) / units_per_sec)
+    }
+
+    /// Number of votes agreeing with `valid`
+    pub fn count(&self, valid: bool) -> u8 {
+        self.votes.iter().filter(|v| v.passed == valid).count() as u8
+    }
+
+    /// Votes on an answer are accepted until `window` after the deadline
+    pub fn is_voting_open(&self, now: i64, window: i64) -> bool {
+        now <= self.deadline_at.saturating_add(window)
+    }
+
+    /// Response deadline: expected time plus tolerance and grace, at least
+    /// `MIN_RESPONSE_WINDOW` after issuance
+    pub fn deadline(issued_at: i64, expected_time_ms: u64, tolerance_bps: u16) -> i64 {
+        let allowed_ms = (expected_time_ms as u128)
+            * (10000 + tolerance_bps as u128)
+            / 10000;
+        let allowed_secs = allowed_ms.div_ceil(1000).min(i64::MAX as u128) as i64;
+
+        issued_at.saturating_add(
+            allowed_secs
+                .saturating_add(Self::RESPONSE_GRACE)
+                .max(Self::MIN_RESPONSE_WINDOW),
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+/// A staked health checker
+///
+/// PDA `[b"checker", authority]`. Stake is escrowed in the config's
+/// checker vault and burned in part when the checker is proven wrong.
+#[account]
+pub struct Checker {
+    /// Checker signing key
+    pub authority: Pubkey,
+
+    /// HYPER escrowed in the checker vault
+    pub stake: u64,
+
+    /// Votes submitted and votes slashed
+    pub votes_submitted: u64,
+    pub times_slashed: u64,
+
+    /// When exit was requested (Some = no longer eligible)
+    pub exit_requested_at: Option<i64>,
+
+    pub registered_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Checker {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // authority
+        8 + // stake
+        8 + // votes_submitted
+        8 + // times_slashed
+        1 + 8 + // exit_requested_at
+        8 + // registered_at
+        1; // bump
+
+    pub fn is_eligible(&self, min_stake: u64) -> bool {
+        self.exit_requested_at.is_none() && self.stake >= min_stake
+    }
+}
+
+/// One checker's signed vote in a health round or on a challenge answer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CheckerVote {
+    pub checker: Pubkey,
+    pub passed: bool,
+    pub slashed: bool,
+}
+
+impl CheckerVote {
+    pub const SPACE: usize = 32 + 1 + 1;
+}
+
+/// Health-check votes on one node within a window
+///
+/// PDA `[b"health_round", node, round_id]`. A node's stats only change
+/// when `quorum` distinct checkers agree; checkers who voted the other
+/// way in a resolved round can be slashed.
+#[account]
+pub struct HealthRound {
+    pub node: Pubkey,
+    pub round_id: u64,
+    pub started_at: i64,
+    pub votes: Vec<CheckerVote>,
+
+    /// Outcome once quorum is reached (Some(passed))
+    pub outcome: Option<bool>,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HealthRound {
+    /// Enough for 2 * MAX_QUORUM - 1 votes (always decides)
+    pub const MAX_VOTES: usize = 15;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // node
+        8 + // round_id
+        8 + // started_at
+        4 + Self::MAX_VOTES * CheckerVote::SPACE + // votes
+        1 + 1 + // outcome
+        1; // bump
+
+    /// Number of votes agreeing with `passed`
+    pub fn count(&self, passed: bool) -> u8 {
+        self.votes.iter().filter(|v| v.passed == passed).count() as u8
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+/// Global hypernode-nodes configuration
+///
+/// Holds the admin and the auditors allowed to attest nodes via `audit`.
+#[account]
+pub struct NodesConfig {
+    /// Admin (manages auditors; hand over to governance PDA)
+    pub admin: Pubkey,
+
+    /// Keys allowed to audit nodes
+    pub auditors: Vec<Pubkey>,
+
+    /// Checker registry (set by `init_checker_registry`)
+    pub checker_mint: Pubkey,
+    pub checker_vault: Pubkey,
+    pub checker_params: CheckerParams,
+
+    /// Seconds without a heartbeat before `mark_stale` may deactivate a node
+    pub stale_after: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl NodesConfig {
+    pub const MAX_AUDITORS: usize = 16;
+
+    /// Default heartbeat staleness threshold (15 minutes)
+    pub const DEFAULT_STALE_AFTER: i64 = 900;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // admin
+        4 + Self::MAX_AUDITORS * 32 + // auditors
+        32 + // checker_mint
+        32 + // checker_vault
+        CheckerParams::SPACE + // checker_params
+        8 + // stale_after
+        1; // bump
+
+    pub fn is_auditor(&self, key: &Pubkey) -> bool {
+        self.auditors.contains(key)
+    }
+}
+
+/// Health-check quorum and checker staking parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CheckerParams {
+    /// Minimum HYPER a checker must escrow to be eligible
+    pub min_stake: u64,
+
+    /// Distinct checkers that must agree before a node's score moves
+    pub quorum: u8,
+
+    /// Seconds a health round stays open for votes
+    pub window: i64,
+
+    /// Share of stake burned when a checker voted against the outcome
+    pub slash_bps: u16,
+
+    /// Cooldown between unregistering and withdrawing stake (seconds)
+    pub unbonding: i64,
+}
+
+impl CheckerParams {
+    pub const SPACE: usize = 8 + 1 + 8 + 2 + 8;
+
+    /// Quorum must fit in a round (2 * quorum - 1 votes always decide)
+    pub const MAX_QUORUM: u8 = 8;
+
+    pub fn is_valid(&self) -> bool {
+        self.min_stake > 0
+            && self.quorum > 0
+            && self.quorum <= Self::MAX_QUORUM
+            && self.window > 0
+            && self.slash_bps <= 10000
+            && self.unbonding >= self.window
+    }
+}
@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+
+/// All 249 officially assigned ISO 3166-1 alpha-2 codes (sorted) and the
+/// continent each belongs to (transcontinental countries: Russia in Europe,
+/// Turkey and the Caucasus in Asia, Cyprus in Europe as an EU member)
+pub const ISO_3166_ALPHA2: [([u8; 2], Region); 249] = {
+    use Region::*;
+    [
+        (*b"AD", Europe), (*b"AE", Asia), (*b"AF", Asia),
+        (*b"AG", NorthAmerica), (*b"AI", NorthAmerica), (*b"AL", Europe),
+        (*b"AM", Asia), (*b"AO", Africa), (*b"AQ", Antarctica),
+        (*b"AR", SouthAmerica), (*b"AS", Oceania), (*b"AT", Europe),
+        (*b"AU", Oceania), (*b"AW", NorthAmerica), (*b"AX", Europe),
+        (*b"AZ", Asia), (*b"BA", Europe), (*b"BB", NorthAmerica),
+        (*b"BD", Asia), (*b"BE", Europe), (*b"BF", Africa),
+        (*b"BG", Europe), (*b"BH", Asia), (*b"BI", Africa),
+        (*b"BJ", Africa), (*b"BL", NorthAmerica), (*b"BM", NorthAmerica),
+        (*b"BN", Asia), (*b"BO", SouthAmerica), (*b"BQ", NorthAmerica),
+        (*b"BR", SouthAmerica), (*b"BS", NorthAmerica), (*b"BT", Asia),
+        (*b"BV", Antarctica), (*b"BW", Africa), (*b"BY", Europe),
+        (*b"BZ", NorthAmerica), (*b"CA", NorthAmerica), (*b"CC", Asia),
+        (*b"CD", Africa), (*b"CF", Africa), (*b"CG", Africa),
+        (*b"CH", Europe), (*b"CI", Africa), (*b"CK", Oceania),
+        (*b"CL", SouthAmerica), (*b"CM", Africa), (*b"CN", Asia),
+        (*b"CO", SouthAmerica), (*b"CR", NorthAmerica), (*b"CU", NorthAmerica),
+        (*b"CV", Africa), (*b"CW", NorthAmerica), (*b"CX", Asia),
+        (*b"CY", Europe), (*b"CZ", Europe), (*b"DE", Europe),
+        (*b"DJ", Africa), (*b"DK", Europe), (*b"DM", NorthAmerica),
+        (*b"DO", NorthAmerica), (*b"DZ", Africa), (*b"EC", SouthAmerica),
+        (*b"EE", Europe), (*b"EG", Africa), (*b"EH", Africa),
+        (*b"ER", Africa), (*b"ES", Europe), (*b"ET", Africa),
+        (*b"FI", Europe), (*b"FJ", Oceania), (*b"FK", SouthAmerica),
+        (*b"FM", Oceania), (*b"FO", Europe), (*b"FR", Europe),
+        (*b"GA", Africa), (*b"GB", Europe), (*b"GD", NorthAmerica),
+        (*b"GE", Asia), (*b"GF", SouthAmerica), (*b"GG", Europe),
+        (*b"GH", Africa), (*b"GI", Europe), (*b"GL", NorthAmerica),
+        (*b"GM", Africa), (*b"GN", Africa), (*b"GP", NorthAmerica),
+        (*b"GQ", Africa), (*b"GR", Europe), (*b"GS", Antarctica),
+        (*b"GT", NorthAmerica), (*b"GU", Oceania), (*b"GW", Africa),
+        (*b"GY", SouthAmerica), (*b"HK", Asia), (*b"HM", Antarctica),
+        (*b"HN", NorthAmerica), (*b"HR", Europe), (*b"HT", NorthAmerica),
+        (*b"HU", Europe), (*b"ID", Asia), (*b"IE", Europe),
+        (*b"IL", Asia), (*b"IM", Europe), (*b"IN", Asia),
+        (*b"IO", Asia), (*b"IQ", Asia), (*b"IR", Asia),
+        (*b"IS", Europe), (*b"IT", Europe), (*b"JE", Europe),
+        (*b"JM", NorthAmerica), (*b"JO", Asia), (*b"JP", Asia),
+        (*b"KE", Africa), (*b"KG", Asia), (*b"KH", Asia),
+        (*b"KI", Oceania), (*b"KM", Africa), (*b"KN", NorthAmerica),
+        (*b"KP", Asia), (*b"KR", Asia), (*b"KW", Asia),
+        (*b"KY", NorthAmerica), (*b"KZ", Asia), (*b"LA", Asia),
+        (*b"LB", Asia), (*b"LC", NorthAmerica), (*b"LI", Europe),
+        (*b"LK", Asia), (*b"LR", Africa), (*b"LS", Africa),
+        (*b"LT", Europe), (*b"LU", Europe), (*b"LV", Europe),
+        (*b"LY", Africa), (*b"MA", Africa), (*b"MC", Europe),
+        (*b"MD", Europe), (*b"ME", Europe), (*b"MF", NorthAmerica),
+        (*b"MG", Africa), (*b"MH", Oceania), (*b"MK", Europe),
+        (*b"ML", Africa), (*b"MM", Asia), (*b"MN", Asia),
+        (*b"MO", Asia), (*b"MP", Oceania), (*b"MQ", NorthAmerica),
+        (*b"MR", Africa), (*b"MS", NorthAmerica), (*b"MT", Europe),
+        (*b"MU", Africa), (*b"MV", Asia), (*b"MW", Africa),
+        (*b"MX", NorthAmerica), (*b"MY", Asia), (*b"MZ", Africa),
+        (*b"NA", Africa), (*b"NC", Oceania), (*b"NE", Africa),
+        (*b"NF", Oceania), (*b"NG", Africa), (*b"NI", NorthAmerica),
+        (*b"NL", Europe), (*b"NO", Europe), (*b"NP", Asia),
+        (*b"NR", Oceania), (*b"NU", Oceania), (*b"NZ", Oceania),
+        (*b"OM", Asia), (*b"PA", NorthAmerica), (*b"PE", SouthAmerica),
+        (*b"PF", Oceania), (*b"PG", Oceania), (*b"PH", Asia),
+        (*b"PK", Asia), (*b"PL", Europe), (*b"PM", NorthAmerica),
+        (*b"PN", Oceania), (*b"PR", NorthAmerica), (*b"PS", Asia),
+        (*b"PT", Europe), (*b"PW", Oceania), (*b"PY", SouthAmerica),
+        (*b"QA", Asia), (*b"RE", Africa), (*b"RO", Europe),
+        (*b"RS", Europe), (*b"RU", Europe), (*b"RW", Africa),
+        (*b"SA", Asia), (*b"SB", Oceania), (*b"SC", Africa),
+        (*b"SD", Africa), (*b"SE", Europe), (*b"SG", Asia),
+        (*b"SH", Africa), (*b"SI", Europe), (*b"SJ", Europe),
+        (*b"SK", Europe), (*b"SL", Africa), (*b"SM", Europe),
+        (*b"SN", Africa), (*b"SO", Africa), (*b"SR", SouthAmerica),
+        (*b"SS", Africa), (*b"ST", Africa), (*b"SV", NorthAmerica),
+        (*b"SX", NorthAmerica), (*b"SY", Asia), (*b"SZ", Africa),
+        (*b"TC", NorthAmerica), (*b"TD", Africa), (*b"TF", Antarctica),
+        (*b"TG", Africa), (*b"TH", Asia), (*b"TJ", Asia),
+        (*b"TK", Oceania), (*b"TL", Asia), (*b"TM", Asia),
+        (*b"TN", Africa), (*b"TO", Oceania), (*b"TR", Asia),
+        (*b"TT", NorthAmerica), (*b"TV", Oceania), (*b"TW", Asia),
+        (*b"TZ", Africa), (*b"UA", Europe), (*b"UG", Africa),
+        (*b"UM", Oceania), (*b"US", NorthAmerica), (*b"UY", SouthAmerica),
+        (*b"UZ", Asia), (*b"VA", Europe), (*b"VC", NorthAmerica),
+        (*b"VE", SouthAmerica), (*b"VG", NorthAmerica), (*b"VI", NorthAmerica),
+        (*b"VN", Asia), (*b"VU", Oceania), (*b"WF", Oceania),
+        (*b"WS", Oceania), (*b"YE", Asia), (*b"YT", Africa),
+        (*b"ZA", Africa), (*b"ZM", Africa), (*b"ZW", Africa),
+    ]
+};
+
+/// Whether `code` is an assigned ISO 3166-1 alpha-2 code (uppercase)
+pub fn is_valid_country(code: &[u8; 2]) -> bool {
+    region_of(code).is_some()
+}
+
+/// Continent of an assigned ISO 3166-1 alpha-2 code
+pub fn region_of(code: &[u8; 2]) -> Option<Region> {
+    ISO_3166_ALPHA2
+        .binary_search_by_key(code, |(country, _)| *country)
+        .ok()
+        .map(|index| ISO_3166_ALPHA2[index].1)
+}
+
+/// Continent-level region of a node (derived from its country)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+/// Where a job's data may be processed
+///
+/// Both constraints apply when set: the node's country must be listed in
+/// `allowed_countries` (empty = any) and its region must equal `region`.
+/// EU-only execution lists the EU member states.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct DataResidency {
+    pub allowed_countries: Vec<[u8; 2]>,
+    pub region: Option<Region>,
+}
+
+impl DataResidency {
+    pub const MAX_COUNTRIES: usize = 32;
+
+    pub const SPACE: usize = 4 + Self::MAX_COUNTRIES * 2 + // allowed_countries
+        1 + 1; // region
+
+    /// Within size limits and only valid country codes
+    pub fn is_valid(&self) -> bool {
+        self.allowed_countries.len() <= Self::MAX_COUNTRIES
+            && self.allowed_countries.iter().all(is_valid_country)
+    }
+
+    /// Whether a node at `country`/`region` may run the job
+    pub fn allows(&self, country: &[u8; 2], region: Region) -> bool {
+        (self.allowed_countries.is_empty() || self.allowed_countries.contains(country))
+            && (self.region.is_none() || self.region == Some(region))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_table_is_sorted() {
+        assert!(ISO_3166_ALPHA2.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn valid_countries() {
+        assert!(is_valid_country(b"DE"));
+        assert!(is_valid_country(b"AD"));
+        assert!(is_valid_country(b"ZW"));
+        assert!(!is_valid_country(b"XX"));
+        assert!(!is_valid_country(b"de"));
+        assert!(!is_valid_country(b"EU"));
+        assert!(!is_valid_country(&[0, 0]));
+    }
+
+    #[test]
+    fn regions() {
+        assert!(region_of(b"FR") == Some(Region::Europe));
+        assert!(region_of(b"CY") == Some(Region::Europe));
+        assert!(region_of(b"TR") == Some(Region::Asia));
+        assert!(region_of(b"US") == Some(Region::NorthAmerica));
+        assert!(region_of(b"XX").is_none());
+    }
+
+    #[test]
+    fn residency_allows() {
+        let any = DataResidency {
+            allowed_countries: vec![],
+            region: None,
+        };
+        assert!(any.allows(b"US", Region::NorthAmerica));
+
+        let eu = DataResidency {
+            allowed_countries: vec![*b"DE", *b"FR"],
+            region: None,
+        };
+        assert!(eu.allows(b"DE", Region::Europe));
+        assert!(!eu.allows(b"CH", Region::Europe));
+
+        let europe = DataResidency {
+            allowed_countries: vec![],
+            region: Some(Region::Europe),
+        };
+        assert!(europe.allows(b"CH", Region::Europe));
+        assert!(!europe.allows(b"US", Region::NorthAmerica));
+
+        let both = DataResidency {
+            allowed_countries: vec![*b"DE", *b"US"],
+            region: Some(Region::Europe),
+        };
+        assert!(both.allows(b"DE", Region::Europe));
+        assert!(!both.allows(b"US", Region::NorthAmerica));
+    }
+
+    #[test]
+    fn residency_validation() {
+        let valid = DataResidency {
+            allowed_countries: vec![*b"DE"],
+            region: None,
+        };
+        assert!(valid.is_valid());
+
+        let invalid_code = DataResidency {
+            allowed_countries: vec![*b"DE", *b"XX"],
+            region: None,
+        };
+        assert!(!invalid_code.is_valid());
+
+        let too_many = DataResidency {
+            allowed_countries: vec![*b"DE"; DataResidency::MAX_COUNTRIES + 1],
+            region: None,
+        };
+        assert!(!too_many.is_valid());
+    }
+}
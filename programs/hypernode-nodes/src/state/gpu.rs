@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+/// Fingerprint of one physical GPU attached to a node
+///
+/// PDA `[b"gpu", unique_device_id]`: the device UUID is the seed, so the
+/// same physical GPU can only be registered once across the network.
+/// The owning node lists its fingerprints in `Node::gpus`.
+#[account]
+pub struct GpuFingerprint {
+    /// Node PDA this GPU is attached to
+    pub node: Pubkey,
+
+    /// Node authority (who registered the GPU)
+    pub authority: Pubkey,
+
+    /// Hardware UUID reported by the driver
+    pub unique_device_id: [u8; 32],
+
+    /// Driver stack
+    pub cuda_version: String,
+    pub driver_version: String,
+
+    /// Measured bandwidths (GB/s)
+    pub pcie_bandwidth: u64,
+    pub memory_bandwidth: u64,
+
+    /// CUDA compute capability, e.g. "8.6"
+    pub compute_capability: String,
+
+    /// Timestamp
+    pub registered_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GpuFingerprint {
+    pub const MAX_CUDA_VERSION_LEN: usize = 16;
+    pub const MAX_DRIVER_VERSION_LEN: usize = 32;
+    pub const MAX_COMPUTE_CAPABILITY_LEN: usize = 8;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // node
+        32 + // authority
+        32 + // unique_device_id
+        4 + Self::MAX_CUDA_VERSION_LEN + // cuda_version
+        4 + Self::MAX_DRIVER_VERSION_LEN + // driver_version
+        8 + // pcie_bandwidth
+        8 + // memory_bandwidth
+        4 + Self::MAX_COMPUTE_CAPABILITY_LEN + // compute_capability
+        8 + // registered_at
+        1; // bump
+}
+
+/// Fingerprint data submitted by the node operator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GpuFingerprintParams {
+    pub unique_device_id: [u8; 32],
+    pub cuda_version: String,
+    pub driver_version: String,
+    pub pcie_bandwidth: u64,
+    pub memory_bandwidth: u64,
+    pub compute_capability: String,
+}
@@ -0,0 +1,338 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Detailed, versioned hardware descriptor for a node
+///
+/// PDA `[b"hardware", node]`, written by `set_hardware`. The account is
+/// reallocated as the accelerator list grows, so multi-GPU and
+/// multi-vendor nodes fit without a fixed cap in `Node`.
+#[account]
+pub struct HardwareDescriptor {
+    /// Node PDA this descriptor belongs to
+    pub node: Pubkey,
+
+    /// Layout version (see `CURRENT_VERSION`)
+    pub version: u8,
+
+    /// Bumped on every `set_hardware`
+    pub revision: u32,
+
+    /// Accelerators (GPUs, TPUs, ...), one entry per model
+    pub accelerators: Vec<Accelerator>,
+
+    /// Total system memory (MB)
+    pub system_memory_mb: u64,
+
+    /// Network bandwidth (Mbps)
+    pub network_bandwidth_mbps: u32,
+
+    /// Supported job runtimes (`Runtime` bit flags)
+    pub runtimes: u8,
+
+    /// Timestamp
+    pub updated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl HardwareDescriptor {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const MAX_ACCELERATORS: usize = 32;
+
+    /// Account size for a given number of accelerator entries
+    pub const fn space(accelerator_count: usize) -> usize {
+        8 + // discriminator
+        32 + // node
+        1 + // version
+        4 + // revision
+        4 + accelerator_count * Accelerator::SPACE + // accelerators
+        8 + // system_memory_mb
+        4 + // network_bandwidth_mbps
+        1 + // runtimes
+        8 + // updated_at
+        1 // bump
+    }
+
+    /// Grow the account (and rent) to hold `accelerator_count` entries
+    pub fn ensure_capacity<'info>(
+        descriptor: &Account<'info, HardwareDescriptor>,
+        accelerator_count: usize,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let info = descriptor.to_account_info();
+        let new_size = Self::space(accelerator_count);
+        if info.data_len() >= new_size {
+            return Ok(());
+        }
+
+        let rent = Rent::get()?.minimum_balance(new_size);
+        let top_up = rent.saturating_sub(info.lamports());
+        if top_up > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.to_account_info(),
+                to: info.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, top_up)?;
+        }
+
+        info.realloc(new_size, false)?;
+
+        Ok(())
+    }
+
+    /// Whether this descriptor already holds exactly this hardware
+    ///
+    /// A freshly created descriptor (revision 0) holds none, so any valid
+    /// submission counts as a change.
+    pub fn describes(
+        &self,
+        accelerators: &[Accelerator],
+        system_memory_mb: u64,
+        network_bandwidth_mbps: u32,
+        runtimes: u8,
+    ) -> bool {
+        self.revision > 0
+            && self.accelerators == accelerators
+            && self.system_memory_mb == system_memory_mb
+            && self.network_bandwidth_mbps == network_bandwidth_mbps
+            && self.runtimes == runtimes
+    }
+
+    pub fn supports(&self, runtime: Runtime) -> bool {
+        self.runtimes & runtime as u8 != 0
+    }
+
+    /// Total VRAM across all accelerators (GB)
+    pub fn total_vram_gb(&self) -> u32 {
+        self.accelerators
+            .iter()
+            .map(|accelerator| accelerator.vram_gb as u32 * accelerator.count as u32)
+            .sum()
+    }
+
+    /// Whether this hardware satisfies a job's requirements
+    pub fn meets(&self, requirements: &HardwareRequirements) -> bool {
+        let matching: u32 = self
+            .accelerators
+            .iter()
+            .filter(|accelerator| {
+                requirements.vendor.unwrap_or(accelerator.vendor) == accelerator.vendor
+                    && accelerator.vram_gb >= requirements.min_vram_gb
+                    && accelerator.compute_capability >= requirements.min_compute_capability
+            })
+            .map(|accelerator| accelerator.count as u32)
+            .sum();
+
+        matching >= requirements.min_accelerators as u32
+            && self.system_memory_mb >= requirements.min_system_memory_mb
+            && self.network_bandwidth_mbps >= requirements.min_network_bandwidth_mbps
+            && self.runtimes & requirements.runtimes == requirements.runtimes
+    }
+}
+
+/// One accelerator model installed in a node
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Accelerator {
+    pub vendor: AcceleratorVendor,
+
+    /// Vendor model identifier, e.g. PCI device id
+    pub model_id: u32,
+
+    /// Memory per device (GB)
+    pub vram_gb: u16,
+
+    /// CUDA-style compute capability (0.0 if not applicable)
+    pub compute_capability: ComputeCapability,
+
+    /// Number of identical devices
+    pub count: u8,
+}
+
+impl Accelerator {
+    pub const SPACE: usize = 1 + 4 + 2 + 2 + 1;
+}
+
+/// Compute capability, e.g. 8.6 = { major: 8, minor: 6 }
+///
+/// Ordered by major, then minor.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default,
+)]
+pub struct ComputeCapability {
+    pub major: u8,
+    pub minor: u8,
+}
+
+/// Accelerator vendors
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AcceleratorVendor {
+    Nvidia,
+    Amd,
+    Intel,
+    Apple,
+    Google,
+    Other,
+}
+
+/// Job runtimes (bit flags in `HardwareDescriptor::runtimes`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Runtime {
+    Docker = 1,
+    Gvisor = 2,
+    Wasm = 4,
+}
+
+impl Runtime {
+    pub const ALL: u8 = Runtime::Docker as u8 | Runtime::Gvisor as u8 | Runtime::Wasm as u8;
+}
+
+/// Hardware a job needs, checked against `HardwareDescriptor::meets`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HardwareRequirements {
+    /// Accelerators matching vendor/VRAM/capability (0 = CPU-only is fine)
+    pub min_accelerators: u8,
+    pub vendor: Option<AcceleratorVendor>,
+    pub min_vram_gb: u16,
+    pub min_compute_capability: ComputeCapability,
+
+    pub min_system_memory_mb: u64,
+    pub min_network_bandwidth_mbps: u32,
+
+    /// Runtimes that must all be supported (`Runtime` bit flags)
+    pub runtimes: u8,
+}
+
+impl HardwareRequirements {
+    pub const SPACE: usize = 1 + (1 + 1) + 2 + 2 + 8 + 4 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accelerator(vendor: AcceleratorVendor, vram_gb: u16, major: u8, count: u8) -> Accelerator {
+        Accelerator {
+            vendor,
+            model_id: 0,
+            vram_gb,
+            compute_capability: ComputeCapability { major, minor: 0 },
+            count,
+        }
+    }
+
+    fn descriptor() -> HardwareDescriptor {
+        HardwareDescriptor {
+            node: Pubkey::default(),
+            version: HardwareDescriptor::CURRENT_VERSION,
+            revision: 1,
+            accelerators: vec![
+                accelerator(AcceleratorVendor::Nvidia, 24, 8, 2),
+                accelerator(AcceleratorVendor::Amd, 16, 0, 1),
+            ],
+            system_memory_mb: 65_536,
+            network_bandwidth_mbps: 1_000,
+            runtimes: Runtime::Docker as u8 | Runtime::Wasm as u8,
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn meets_empty_requirements() {
+        assert!(descriptor().meets(&HardwareRequirements::default()));
+    }
+
+    #[test]
+    fn meets_accelerators() {
+        let hardware = descriptor();
+        let nvidia = HardwareRequirements {
+            min_accelerators: 2,
+            vendor: Some(AcceleratorVendor::Nvidia),
+            min_vram_gb: 16,
+            min_compute_capability: ComputeCapability { major: 8, minor: 0 },
+            ..HardwareRequirements::default()
+        };
+        assert!(hardware.meets(&nvidia));
+
+        // Only two devices are Nvidia
+        assert!(!hardware.meets(&HardwareRequirements {
+            min_accelerators: 3,
+            ..nvidia
+        }));
+
+        // Any vendor: all three devices have 16 GB
+        assert!(hardware.meets(&HardwareRequirements {
+            min_accelerators: 3,
+            vendor: None,
+            min_compute_capability: ComputeCapability::default(),
+            ..nvidia
+        }));
+
+        assert!(!hardware.meets(&HardwareRequirements {
+            min_vram_gb: 32,
+            ..nvidia
+        }));
+        assert!(!hardware.meets(&HardwareRequirements {
+            min_compute_capability: ComputeCapability { major: 8, minor: 6 },
+            ..nvidia
+        }));
+    }
+
+    #[test]
+    fn meets_system_requirements() {
+        let hardware = descriptor();
+        let base = HardwareRequirements::default();
+
+        assert!(hardware.meets(&HardwareRequirements {
+            min_system_memory_mb: 65_536,
+            min_network_bandwidth_mbps: 1_000,
+            runtimes: Runtime::Docker as u8,
+            ..base
+        }));
+        assert!(!hardware.meets(&HardwareRequirements {
+            min_system_memory_mb: 65_537,
+            ..base
+        }));
+        assert!(!hardware.meets(&HardwareRequirements {
+            min_network_bandwidth_mbps: 10_000,
+            ..base
+        }));
+        assert!(!hardware.meets(&HardwareRequirements {
+            runtimes: Runtime::Docker as u8 | Runtime::Gvisor as u8,
+            ..base
+        }));
+    }
+
+    #[test]
+    fn new_descriptor_describes_nothing() {
+        let hardware = descriptor();
+        let fresh = HardwareDescriptor {
+            revision: 0,
+            accelerators: vec![],
+            system_memory_mb: 0,
+            network_bandwidth_mbps: 0,
+            runtimes: 0,
+            ..descriptor()
+        };
+        let describes = |target: &HardwareDescriptor| {
+            target.describes(
+                &hardware.accelerators,
+                hardware.system_memory_mb,
+                hardware.network_bandwidth_mbps,
+                hardware.runtimes,
+            )
+        };
+
+        // First submission always counts as a change (clears the audit)
+        assert!(!describes(&fresh));
+        assert!(describes(&hardware));
+        assert!(!describes(&HardwareDescriptor {
+            system_memory_mb: 32_768,
+            ..descriptor()
+        }));
+    }
+}
@@ -1,3 +1,19 @@
 pub mod node;
+pub mod operator;
+pub mod config;
+pub mod checker;
+pub mod challenge;
+pub mod gpu;
+pub mod uptime;
+pub mod hardware;
+pub mod geo;
 
 pub use node::*;
+pub use operator::*;
+pub use config::*;
+pub use checker::*;
+pub use challenge::*;
+pub use gpu::*;
+pub use uptime::*;
+pub use hardware::*;
+pub use geo::*;
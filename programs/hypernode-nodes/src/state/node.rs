@@ -1,4 +1,20 @@
 use anchor_lang::prelude::*;
+use hypernode_reputation::ReputationInputs;
+use super::{ComputeChallenge, Region, UptimeTracker};
+
+/// hypernode-staking program (owner of node delegation accounts)
+///
+/// Hardcoded because hypernode-staking depends on this crate.
+pub mod staking_program {
+    anchor_lang::declare_id!("HYPRstake1111111111111111111111111111111111");
+}
+
+/// hypernode-jobs program (reports job outcomes via CPI)
+///
+/// Hardcoded because hypernode-jobs depends on this crate.
+pub mod jobs_program {
+    anchor_lang::declare_id!("HYPRjobs11111111111111111111111111111111111");
+}
 
 /// Node account storing hardware specs and stats
 #[account]
@@ -12,21 +28,34 @@ pub struct Node {
     /// Whether node has been audited by trusted authority
     pub is_audited: bool,
 
+    /// Audit attestation (set by `audit`, cleared on hardware changes)
+    pub audited_by: Option<Pubkey>,
+    pub audit_attestation: [u8; 32],
+    pub audit_expires_at: i64,
+
+    /// Audits revoked by an auditor (reputation penalty)
+    pub audit_failures: u32,
+
     /// Hardware specifications
     pub architecture: Architecture,
-    pub country: Country,
+    pub country: [u8; 2], // ISO 3166-1 alpha-2, e.g. *b"SG"
+    pub region: Region,
     pub cpu_cores: u16,
     pub gpu_cores: u16,
     pub ram_gb: u16,
     pub iops: u32,
     pub storage_gb: u32,
 
+    /// GPU fingerprint PDAs attached to this node (one per device)
+    pub gpus: Vec<Pubkey>,
+
     /// Performance stats
     pub jobs_completed: u64,
     pub jobs_failed: u64,
     pub total_earned: u64,
-    pub reputation_score: u16, // 0-1000
-    pub uptime_percentage: u8, // 0-100
+    pub reputation_score: u16, // 0-1000, see `refresh_reputation`
+    pub uptime_percentage: u8, // 0-100, from `uptime` over 30 days
+    pub uptime: UptimeTracker,
 
     /// Network information
     pub endpoint: String, // HTTP endpoint for logs
@@ -37,6 +66,12 @@ pub struct Node {
     pub last_heartbeat: i64,
     pub is_active: bool,
 
+    /// Taken out of service by the operator (heartbeat won't revive it)
+    pub deactivated: bool,
+
+    /// Jobs currently running on this node (reported by hypernode-jobs)
+    pub active_jobs: u32,
+
     /// Health check stats (Checker system)
     pub last_health_check: i64,
     pub total_health_checks: u64,
@@ -44,6 +79,34 @@ pub struct Node {
     pub failed_health_checks: u64,
     pub health_check_pass_rate: u8, // 0-100
 
+    /// Current health round (checker quorum) and when it opened (0 = none)
+    pub health_round: u64,
+    pub health_round_started_at: i64,
+
+    /// Compute challenge stats (hardware attestation)
+    pub challenges_passed: u32,
+    pub challenge_failures: u32,
+    pub consecutive_challenge_failures: u8,
+
+    /// When the last challenge was issued (see `CHALLENGE_COOLDOWN`)
+    pub last_challenge_at: i64,
+
+    /// Outstanding audit/challenge penalty points (decaying) and when they
+    /// were last updated
+    pub penalty_points: u64,
+    pub penalty_updated_at: i64,
+
+    /// HYPER carried over from the legacy node-registry, held in the
+    /// `[b"legacy_stake_vault"]` account. Not slashable: it sits outside
+    /// hypernode-staking, so misbehaviour only costs reputation
+    ///
+    /// - `legacy_stake`: counts toward market stake until unstaked
+    /// - `legacy_unstaking`: leaving (no market weight), withdrawable after
+    ///   `legacy_unstake_available_at`
+    pub legacy_stake: u64,
+    pub legacy_unstaking: u64,
+    pub legacy_unstake_available_at: i64,
+
     /// PDA bump
     pub bump: u8,
 }
@@ -51,34 +114,108 @@ pub struct Node {
 impl Node {
     pub const MAX_NODE_ID_LEN: usize = 64;
     pub const MAX_ENDPOINT_LEN: usize = 128;
+    pub const MAX_GPUS: usize = 16;
 
     pub const SPACE: usize = 8 + // discriminator
         32 + // authority
         4 + Self::MAX_NODE_ID_LEN + // node_id
         1 + // is_audited
+        1 + 32 + // audited_by (Option<Pubkey>)
+        32 + // audit_attestation
+        8 + // audit_expires_at
+        4 + // audit_failures
         1 + // architecture (enum)
-        1 + // country (enum)
+        2 + // country (ISO 3166-1 alpha-2)
+        1 + // region
         2 + // cpu_cores
         2 + // gpu_cores
         2 + // ram_gb
         4 + // iops
         4 + // storage_gb
+        4 + Self::MAX_GPUS * 32 + // gpus
         8 + // jobs_completed
         8 + // jobs_failed
         8 + // total_earned
         2 + // reputation_score
         1 + // uptime_percentage
+        UptimeTracker::SPACE + // uptime
         4 + Self::MAX_ENDPOINT_LEN + // endpoint
         4 + // version
         8 + // registered_at
         8 + // last_heartbeat
         1 + // is_active
+        1 + // deactivated
+        4 + // active_jobs
         8 + // last_health_check
         8 + // total_health_checks
         8 + // passed_health_checks
         8 + // failed_health_checks
         1 + // health_check_pass_rate
+        8 + // health_round
+        8 + // health_round_started_at
+        4 + // challenges_passed
+        4 + // challenge_failures
+        1 + // consecutive_challenge_failures
+        8 + // last_challenge_at
+        8 + // penalty_points
+        8 + // penalty_updated_at
+        8 + // legacy_stake
+        8 + // legacy_unstaking
+        8 + // legacy_unstake_available_at
         1; // bump
+
+    /// Whether the audit is set and not expired
+    pub fn is_audit_valid(&self, at_timestamp: i64) -> bool {
+        self.is_audited && at_timestamp < self.audit_expires_at
+    }
+
+    /// Too many consecutive failed challenges (heartbeat won't revive it)
+    pub fn is_challenge_suspended(&self) -> bool {
+        self.consecutive_challenge_failures >= ComputeChallenge::MAX_CONSECUTIVE_FAILURES
+    }
+
+    /// Whether jobs may be assigned to the node: in service, not marked
+    /// stale and not suspended by failed challenges
+    pub fn can_take_jobs(&self) -> bool {
+        self.is_active && !self.deactivated && !self.is_challenge_suspended()
+    }
+
+    /// Recompute `reputation_score` with the shared reputation formula
+    ///
+    /// Combines job success, health-check pass rate, uptime, decaying
+    /// audit/challenge penalties, and decay since the last heartbeat.
+    pub fn refresh_reputation(&mut self, now: i64) {
+        self.reputation_score = hypernode_reputation::score(&ReputationInputs {
+            jobs_completed: self.jobs_completed,
+            jobs_failed: self.jobs_failed,
+            health_checks_passed: self.passed_health_checks,
+            health_checks_failed: self.failed_health_checks,
+            uptime_percentage: self.uptime_percentage,
+            penalty_points: self.penalty_points,
+            penalty_updated_at: self.penalty_updated_at,
+            last_active_at: self.last_heartbeat,
+            now,
+        });
+    }
+
+    /// Record a failed audit/challenge penalty (decays over time)
+    pub fn add_penalty(&mut self, audit_failures: u32, challenge_failures: u32, now: i64) {
+        self.penalty_points = hypernode_reputation::add_penalty(
+            self.penalty_points,
+            self.penalty_updated_at,
+            now,
+            hypernode_reputation::penalties(audit_failures, challenge_failures),
+        );
+        self.penalty_updated_at = now;
+    }
+
+    /// Drop audit status (hardware no longer matches the attestation)
+    pub fn clear_audit(&mut self) {
+        self.is_audited = false;
+        self.audited_by = None;
+        self.audit_attestation = [0u8; 32];
+        self.audit_expires_at = 0;
+    }
 }
 
 /// Hardware architecture types
@@ -97,18 +234,92 @@ pub enum Architecture {
     Riscv64,
 }
 
-/// Country codes (ISO 3166-1 alpha-2)
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum Country {
-    US, // United States
-    BR, // Brazil
-    DE, // Germany
-    JP, // Japan
-    CN, // China
-    GB, // United Kingdom
-    FR, // France
-    CA, // Canada
-    AU, // Australia
-    IN, // India
-    Unknown,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(now: i64) -> Node {
+        Node {
+            authority: Pubkey::new_unique(),
+            node_id: "node-1".to_string(),
+            is_audited: false,
+            audited_by: None,
+            audit_attestation: [0u8; 32],
+            audit_expires_at: 0,
+            audit_failures: 0,
+            architecture: Architecture::Amd64,
+            country: *b"SG",
+            region: Region::Asia,
+            cpu_cores: 16,
+            gpu_cores: 0,
+            ram_gb: 64,
+            iops: 10_000,
+            storage_gb: 1_000,
+            gpus: Vec::new(),
+            jobs_completed: 0,
+            jobs_failed: 0,
+            total_earned: 0,
+            reputation_score: 500,
+            uptime_percentage: 0,
+            uptime: UptimeTracker::new(now),
+            endpoint: String::new(),
+            version: 1,
+            registered_at: now,
+            last_heartbeat: now,
+            is_active: true,
+            deactivated: false,
+            active_jobs: 0,
+            last_health_check: 0,
+            total_health_checks: 0,
+            passed_health_checks: 0,
+            failed_health_checks: 0,
+            health_check_pass_rate: 0,
+            health_round: 0,
+            health_round_started_at: 0,
+            challenges_passed: 0,
+            challenge_failures: 0,
+            consecutive_challenge_failures: 0,
+            last_challenge_at: 0,
+            penalty_points: 0,
+            penalty_updated_at: 0,
+            legacy_stake: 0,
+            legacy_unstaking: 0,
+            legacy_unstake_available_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn active_node_takes_jobs() {
+        assert!(node(1_000).can_take_jobs());
+    }
+
+    #[test]
+    fn stale_node_takes_no_jobs() {
+        let mut stale = node(1_000);
+        // As `mark_stale` leaves it; only a heartbeat revives it
+        stale.is_active = false;
+        assert!(!stale.can_take_jobs());
+        assert!(!stale.deactivated);
+    }
+
+    #[test]
+    fn suspended_node_takes_no_jobs() {
+        let mut suspended = node(1_000);
+        // As `verify_challenge` leaves it after repeated failures
+        suspended.consecutive_challenge_failures = ComputeChallenge::MAX_CONSECUTIVE_FAILURES;
+        suspended.is_active = false;
+        assert!(!suspended.can_take_jobs());
+
+        // Still suspended if the flag were set again (e.g. a heartbeat)
+        suspended.is_active = true;
+        assert!(!suspended.can_take_jobs());
+    }
+
+    #[test]
+    fn deactivated_node_takes_no_jobs() {
+        let mut deactivated = node(1_000);
+        deactivated.deactivated = true;
+        assert!(!deactivated.can_take_jobs());
+    }
 }
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Index of all nodes owned by one authority
+///
+/// PDA `[b"operator", authority]`, created on first `register` and grown
+/// (realloc) as nodes are added, so a fleet can be enumerated from a
+/// single account instead of `getProgramAccounts` scans.
+#[account]
+pub struct Operator {
+    /// Wallet owning the nodes
+    pub authority: Pubkey,
+
+    /// Node PDAs owned by this authority
+    pub nodes: Vec<Pubkey>,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Operator {
+    /// Account size for a given number of nodes
+    pub const fn space(node_count: usize) -> usize {
+        8 + // discriminator
+        32 + // authority
+        4 + node_count * 32 + // nodes
+        1 // bump
+    }
+
+    /// Grow the account (and rent) to hold `node_count` nodes
+    pub fn ensure_capacity<'info>(
+        operator: &Account<'info, Operator>,
+        node_count: usize,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let info = operator.to_account_info();
+        let new_size = Self::space(node_count);
+        if info.data_len() >= new_size {
+            return Ok(());
+        }
+
+        let rent = Rent::get()?.minimum_balance(new_size);
+        let top_up = rent.saturating_sub(info.lamports());
+        if top_up > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.to_account_info(),
+                to: info.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, top_up)?;
+        }
+
+        info.realloc(new_size, false)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+/// Rolling heartbeat bitmap over a 30-day window
+///
+/// Time is split into hourly buckets; a bucket's bit is set when the node
+/// heartbeats during it. The bitmap is a ring of `WINDOW_BUCKETS` bits
+/// indexed by `bucket % WINDOW_BUCKETS`, and buckets that slid out of the
+/// window are cleared lazily on the next update.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct UptimeTracker {
+    /// Most recent bucket the bitmap has been advanced to
+    pub last_bucket: u64,
+
+    /// First bucket the node existed in (caps the denominator early on)
+    pub first_bucket: u64,
+
+    /// Heartbeat bits (720 used)
+    pub bitmap: [u64; 12],
+}
+
+impl UptimeTracker {
+    /// Bucket length (seconds)
+    pub const BUCKET_SECONDS: i64 = 3600;
+
+    /// 30 days of hourly buckets
+    pub const WINDOW_BUCKETS: u64 = 720;
+
+    pub const SPACE: usize = 8 + 8 + 12 * 8;
+
+    pub fn bucket(timestamp: i64) -> u64 {
+        (timestamp.max(0) / Self::BUCKET_SECONDS) as u64
+    }
+
+    /// Start tracking at `timestamp` (registration)
+    pub fn new(timestamp: i64) -> Self {
+        let bucket = Self::bucket(timestamp);
+        Self {
+            last_bucket: bucket,
+            first_bucket: bucket,
+            bitmap: [0u64; 12],
+        }
+    }
+
+    fn set_bit(&mut self, bucket: u64, value: bool) {
+        let index = (bucket % Self::WINDOW_BUCKETS) as usize;
+        let mask = 1u64 << (index % 64);
+        if value {
+            self.bitmap[index / 64] |= mask;
+        } else {
+            self.bitmap[index / 64] &= !mask;
+        }
+    }
+
+    /// Move the window to `timestamp`, clearing buckets with no heartbeat
+    pub fn advance(&mut self, timestamp: i64) {
+        let bucket = Self::bucket(timestamp);
+        if bucket <= self.last_bucket {
+            return;
+        }
+        if bucket - self.last_bucket >= Self::WINDOW_BUCKETS {
+            self.bitmap = [0u64; 12];
+        } else {
+            for missed in (self.last_bucket + 1)..=bucket {
+                self.set_bit(missed, false);
+            }
+        }
+        self.last_bucket = bucket;
+    }
+
+    /// Mark the bucket containing `timestamp` as up
+    pub fn record(&mut self, timestamp: i64) {
+        self.advance(timestamp);
+        self.set_bit(Self::bucket(timestamp), true);
+    }
+
+    /// Share of buckets with a heartbeat in the window (0-100)
+    pub fn percentage(&self) -> u8 {
+        let elapsed = self.last_bucket.saturating_sub(self.first_bucket) + 1;
+        let window = elapsed.min(Self::WINDOW_BUCKETS);
+        let up: u64 = self.bitmap.iter().map(|word| word.count_ones() as u64).sum();
+        (up.min(window) * 100 / window) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = UptimeTracker::BUCKET_SECONDS;
+
+    fn up_buckets(tracker: &UptimeTracker) -> u32 {
+        tracker.bitmap.iter().map(|word| word.count_ones()).sum()
+    }
+
+    #[test]
+    fn new_node_is_fully_up() {
+        let mut tracker = UptimeTracker::new(10 * HOUR);
+        tracker.record(10 * HOUR + 5);
+        assert_eq!(tracker.percentage(), 100);
+    }
+
+    #[test]
+    fn missed_buckets_count_against_uptime() {
+        let mut tracker = UptimeTracker::new(0);
+        tracker.record(0);
+        tracker.record(2 * HOUR);
+        assert_eq!(tracker.percentage(), 66);
+
+        // Advancing alone adds empty buckets
+        tracker.advance(3 * HOUR);
+        assert_eq!(tracker.percentage(), 50);
+
+        // Going back in time is ignored
+        tracker.advance(HOUR);
+        assert_eq!(tracker.last_bucket, 3);
+    }
+
+    #[test]
+    fn window_wraps() {
+        let mut tracker = UptimeTracker::new(0);
+        for hour in 0..1_000 {
+            tracker.record(hour * HOUR);
+        }
+        assert_eq!(tracker.percentage(), 100);
+        assert_eq!(up_buckets(&tracker), 720);
+
+        // Half a window without heartbeats, then one more
+        tracker.record((999 + 360) * HOUR);
+        assert_eq!(up_buckets(&tracker), 361);
+        assert_eq!(tracker.percentage(), 50);
+    }
+
+    #[test]
+    fn gap_of_a_full_window_clears_bitmap() {
+        let mut tracker = UptimeTracker::new(0);
+        for hour in 0..10 {
+            tracker.record(hour * HOUR);
+        }
+
+        tracker.record((9 + 720) * HOUR);
+        assert_eq!(up_buckets(&tracker), 1);
+        assert_eq!(tracker.percentage(), 0);
+
+        let mut tracker = UptimeTracker::new(0);
+        tracker.record(0);
+        tracker.advance(10_000 * HOUR);
+        assert_eq!(up_buckets(&tracker), 0);
+        assert_eq!(tracker.last_bucket, 10_000);
+    }
+}
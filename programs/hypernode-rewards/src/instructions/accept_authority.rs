@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Accept a proposed pool authority (step 2 of 2)
+///
+/// Must be signed by the key set in `transfer_authority`.
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let pool = &mut ctx.accounts.reward_pool;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    require!(
+        pool.pending_authority == Some(new_authority),
+        RewardError::NoPendingAuthority
+    );
+
+    let old_authority = pool.authority;
+    pool.authority = new_authority;
+    pool.pending_authority = None;
+
+    msg!("Authority transferred: {} -> {}", old_authority, new_authority);
+
+    emit!(AuthorityTransferredEvent {
+        old_authority,
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub new_authority: Signer<'info>,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::NodeDelegation;
+use crate::state::*;
+
+/// Deposit node earnings and split them (O(1))
+///
+/// - The operator's effective commission goes straight to the operator
+/// - The rest is reflected to delegators that entered the pool, by xNOS
+/// - With no entered delegators, the operator receives everything
+pub fn add_node_earnings(ctx: Context<AddNodeEarnings>, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.node_reward_pool;
+    let delegation = &ctx.accounts.node_delegation;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, RewardError::InvalidAmount);
+
+    let (commission, delegator_share) = NodeRewardPool::split(
+        amount,
+        delegation.commission_at(clock.unix_timestamp),
+        pool.entered_xnos,
+    )?;
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    // Operator commission
+    if commission > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.depositor_token_account.to_account_info(),
+            to: ctx.accounts.operator_token_account.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+        token::transfer(cpi_ctx, commission)?;
+    }
+
+    // Delegator share into node vault
+    if delegator_share > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.depositor_token_account.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, delegator_share)?;
+    }
+
+    pool.accrue(delegator_share)?;
+    pool.total_commission = pool.total_commission.saturating_add(commission);
+    pool.total_distributed = pool.total_distributed.saturating_add(delegator_share);
+
+    msg!(
+        "Node {} earnings: {} commission, {} to delegators",
+        pool.node,
+        commission,
+        delegator_share
+    );
+
+    emit!(NodeEarningsEvent {
+        node: pool.node,
+        amount,
+        commission,
+        delegator_share,
+        entered_xnos: pool.entered_xnos,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddNodeEarnings<'info> {
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_reward_pool.node.as_ref()],
+        bump = node_reward_pool.bump
+    )]
+    pub node_reward_pool: Account<'info, NodeRewardPool>,
+
+    /// Node vault (delegator rewards)
+    #[account(
+        mut,
+        address = node_reward_pool.vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Node's delegation (commission and delegated xNOS)
+    #[account(
+        seeds = [b"delegation", node_reward_pool.node.as_ref()],
+        bump = node_delegation.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Operator's token account (commission destination)
+    #[account(
+        mut,
+        constraint = operator_token_account.owner == node_delegation.operator,
+        constraint = operator_token_account.mint == vault.mint
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    /// Earnings source
+    #[account(
+        mut,
+        constraint = depositor_token_account.owner == depositor.key()
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone depositing node earnings (operator, jobs program)
+    pub depositor: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct NodeEarningsEvent {
+    pub node: Pubkey,
+    pub amount: u64,
+    pub commission: u64,
+    pub delegator_share: u64,
+    pub entered_xnos: u64,
+    pub timestamp: i64,
+}
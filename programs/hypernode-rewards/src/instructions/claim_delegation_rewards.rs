@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::StakeAccount;
+use crate::state::*;
+
+/// Claim a delegated position's share of node earnings (O(1))
+///
+/// Pays rewards settled by delegate/undelegate plus those pending since the
+/// last checkpoint. Pending rewards use min(xNOS at last checkpoint, current
+/// delegated xNOS), so topping up cannot claim retroactively while slashes
+/// apply at once.
+pub fn claim_delegation_rewards(
+    ctx: Context<ClaimDelegationRewards>,
+    _position_id: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.node_reward_pool;
+    let stake_account = &ctx.accounts.stake_account;
+    let delegator = &mut ctx.accounts.delegator_rewards;
+
+    let current_xnos = if stake_account.delegated_to == Some(pool.node) {
+        stake_account.xnos
+    } else {
+        0
+    };
+    let xnos = pool.settle(delegator, current_xnos, current_xnos)?;
+    let claimable = delegator.owed;
+
+    if claimable > 0 {
+        let node = pool.node;
+        let seeds = &[b"node_rewards".as_ref(), node.as_ref(), &[pool.bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token::transfer(cpi_ctx, claimable)?;
+    }
+
+    delegator.owed = 0;
+    pool.total_claimed = pool.total_claimed.saturating_add(claimable);
+
+    msg!(
+        "Claimed {} node rewards for {} delegated xNOS",
+        claimable,
+        xnos
+    );
+
+    emit!(DelegationClaimEvent {
+        user: ctx.accounts.authority.key(),
+        node: pool.node,
+        position_id: stake_account.position_id,
+        amount: claimable,
+        xnos,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct ClaimDelegationRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_reward_pool.node.as_ref()],
+        bump = node_reward_pool.bump
+    )]
+    pub node_reward_pool: Account<'info, NodeRewardPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"delegator",
+            stake_account.key().as_ref(),
+            node_reward_pool.node.as_ref()
+        ],
+        bump = delegator_rewards.bump
+    )]
+    pub delegator_rewards: Account<'info, DelegatorRewards>,
+
+    /// Delegated stake position
+    #[account(
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Node vault (delegator rewards)
+    #[account(
+        mut,
+        address = node_reward_pool.vault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == authority.key()
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct DelegationClaimEvent {
+    pub user: Pubkey,
+    pub node: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub xnos: u64,
+    pub timestamp: i64,
+}
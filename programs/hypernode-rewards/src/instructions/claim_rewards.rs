@@ -1,33 +1,48 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::{Staker, StakingConfig};
 use crate::state::*;
 
 /// Claim rewards based on xNOS (O(1) distribution)
-pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+///
+/// Uses the wallet's aggregate xNOS across all stake positions.
+/// When staking runs in vote-escrow mode, the decayed xNOS is used instead
+/// and all active stake positions must be passed as remaining accounts.
+pub fn claim_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimRewards<'info>>,
+) -> Result<()> {
     let pool = &mut ctx.accounts.reward_pool;
-    let stake_account = &ctx.accounts.stake_account;
+    let staker = &ctx.accounts.staker;
     let staking_config = &ctx.accounts.staking_config;
+    let clock = Clock::get()?;
+
+    let xnos = if staking_config.xnos_decay_enabled {
+        staker.voting_power(staking_config, ctx.remaining_accounts, clock.unix_timestamp)?
+    } else {
+        staker.xnos
+    };
 
     require!(pool.enabled, RewardError::RewardsDisabled);
-    require!(stake_account.xnos > 0, RewardError::NoStake);
+    require!(xnos > 0, RewardError::NoStake);
     require!(pool.total_rewards > 0, RewardError::NoRewards);
 
     // Calculate claimable rewards (O(1))
     let claimable = pool.calculate_claimable(
-        stake_account.xnos,
+        xnos,
         staking_config.total_xnos,
     )?;
 
     require!(claimable > 0, RewardError::NoRewards);
 
     // Transfer rewards
-    let seeds = &[b"reward_pool", &[pool.bump]];
+    let seeds = &[b"reward_pool".as_ref(), &[pool.bump]];
     let signer = &[&seeds[..]];
 
     let cpi_accounts = Transfer {
         from: ctx.accounts.reward_vault.to_account_info(),
         to: ctx.accounts.user_token_account.to_account_info(),
-        authority: ctx.accounts.reward_pool.to_account_info(),
+        authority: pool.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
@@ -39,13 +54,13 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     pool.total_claimed = pool.total_claimed.saturating_add(claimable);
     pool.total_stakers_rewarded = pool.total_stakers_rewarded.saturating_add(1);
 
-    msg!("Claimed {} rewards for {} xNOS", claimable, stake_account.xnos);
+    msg!("Claimed {} rewards for {} xNOS", claimable, xnos);
 
     emit!(ClaimEvent {
         user: ctx.accounts.authority.key(),
         amount: claimable,
-        xnos: stake_account.xnos,
-        timestamp: Clock::get()?.unix_timestamp,
+        xnos,
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -68,13 +83,13 @@ pub struct ClaimRewards<'info> {
     )]
     pub staking_config: Account<'info, StakingConfig>,
 
-    /// User's stake account (for xNOS)
+    /// User's aggregate stake across positions (for xNOS)
     #[account(
-        seeds = [b"stake", authority.key().as_ref()],
-        bump = stake_account.bump,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump,
         seeds::program = staking_program.key()
     )]
-    pub stake_account: Account<'info, StakeAccount>,
+    pub staker: Account<'info, Staker>,
 
     #[account(mut)]
     pub reward_vault: Account<'info, TokenAccount>,
@@ -87,27 +102,12 @@ pub struct ClaimRewards<'info> {
 
     pub authority: Signer<'info>,
 
-    /// CHECK: Staking program address
-    pub staking_program: AccountInfo<'info>,
+    /// Staking program (owner of config and staker accounts)
+    pub staking_program: Program<'info, HypernodeStaking>,
 
     pub token_program: Program<'info, Token>,
 }
 
-/// Placeholder types (would import from staking program)
-#[account]
-pub struct StakingConfig {
-    pub authority: Pubkey,
-    pub total_xnos: u64,
-    pub bump: u8,
-}
-
-#[account]
-pub struct StakeAccount {
-    pub authority: Pubkey,
-    pub xnos: u64,
-    pub bump: u8,
-}
-
 #[event]
 pub struct ClaimEvent {
     pub user: Pubkey,
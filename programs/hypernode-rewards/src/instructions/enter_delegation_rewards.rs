@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::StakeAccount;
+use crate::state::*;
+
+/// Start earning node rewards for a delegated stake position
+///
+/// Checkpoints the position at the pool's current rate; earnings deposited
+/// before entering are not claimable. Call right after `delegate`. Once
+/// entered, later undelegate/delegate calls to this node settle and
+/// re-checkpoint the position automatically.
+pub fn enter_delegation_rewards(
+    ctx: Context<EnterDelegationRewards>,
+    _position_id: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.node_reward_pool;
+    let stake_account = &ctx.accounts.stake_account;
+    let delegator = &mut ctx.accounts.delegator_rewards;
+
+    require!(
+        stake_account.delegated_to == Some(pool.node),
+        RewardError::NotDelegatedToNode
+    );
+
+    delegator.stake_account = stake_account.key();
+    delegator.node = pool.node;
+    delegator.last_reward_per_xnos = pool.reward_per_xnos;
+    delegator.xnos_snapshot = stake_account.xnos;
+    delegator.owed = 0;
+    delegator.bump = ctx.bumps.delegator_rewards;
+
+    msg!(
+        "Position {} entered node {} rewards with {} xNOS",
+        stake_account.position_id,
+        pool.node,
+        stake_account.xnos
+    );
+
+    pool.entered_xnos = pool
+        .entered_xnos
+        .checked_add(stake_account.xnos)
+        .ok_or(RewardError::CalculationOverflow)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct EnterDelegationRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_reward_pool.node.as_ref()],
+        bump = node_reward_pool.bump
+    )]
+    pub node_reward_pool: Account<'info, NodeRewardPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = DelegatorRewards::SPACE,
+        seeds = [
+            b"delegator",
+            stake_account.key().as_ref(),
+            node_reward_pool.node.as_ref()
+        ],
+        bump
+    )]
+    pub delegator_rewards: Account<'info, DelegatorRewards>,
+
+    /// Delegated stake position
+    #[account(
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub system_program: Program<'info, System>,
+}
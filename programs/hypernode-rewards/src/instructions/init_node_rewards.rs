@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use hypernode_staking::program::HypernodeStaking;
+use hypernode_staking::state::{NodeDelegation, StakingConfig};
+use crate::state::*;
+
+/// Create a node's earnings pool and vault (operator only)
+///
+/// Requires the node to be open for delegation in hypernode-staking. The
+/// vault holds HYPER (the staking mint), the token markets pay jobs in.
+pub fn init_node_rewards(ctx: Context<InitNodeRewards>) -> Result<()> {
+    let pool = &mut ctx.accounts.node_reward_pool;
+
+    pool.node = ctx.accounts.node_delegation.node;
+    pool.vault = ctx.accounts.vault.key();
+    pool.reward_per_xnos = 0;
+    pool.entered_xnos = 0;
+    pool.total_distributed = 0;
+    pool.total_commission = 0;
+    pool.total_claimed = 0;
+    pool.bump = ctx.bumps.node_reward_pool;
+
+    msg!("Node reward pool initialized for node {}", pool.node);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitNodeRewards<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = NodeRewardPool::SPACE,
+        seeds = [b"node_rewards", node_delegation.node.as_ref()],
+        bump
+    )]
+    pub node_reward_pool: Account<'info, NodeRewardPool>,
+
+    /// Vault for delegator rewards (PDA, pool is token authority)
+    #[account(
+        init,
+        payer = operator,
+        token::mint = mint,
+        token::authority = node_reward_pool,
+        seeds = [b"node_rewards_vault", node_delegation.node.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Node's delegation (for operator check)
+    #[account(
+        seeds = [b"delegation", node_delegation.node.as_ref()],
+        bump = node_delegation.bump,
+        seeds::program = staking_program.key(),
+        has_one = operator @ RewardError::Unauthorized
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Staking configuration (HYPER mint)
+    #[account(
+        seeds = [b"config"],
+        bump = staking_config.bump,
+        seeds::program = staking_program.key()
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    /// Reward token mint (HYPER)
+    #[account(address = staking_config.mint @ RewardError::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub staking_program: Program<'info, HypernodeStaking>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -11,6 +11,7 @@ pub fn initialize_pool(
     require!(reward_rate_bps <= 10000, RewardError::InvalidRate);
 
     pool.authority = ctx.accounts.authority.key();
+    pool.pending_authority = None;
     pool.total_rewards = 0;
     pool.total_claimed = 0;
     pool.reward_rate_bps = reward_rate_bps;
@@ -40,9 +41,3 @@ pub struct InitializePool<'info> {
 
     pub system_program: Program<'info, System>,
 }
-
-#[error_code]
-pub enum RewardError {
-    #[msg("Reward rate must be <= 10000 bps (100%)")]
-    InvalidRate,
-}
@@ -1,5 +1,25 @@
 pub mod initialize_pool;
 pub mod claim_rewards;
+pub mod set_enabled;
+pub mod set_reward_rate;
+pub mod transfer_authority;
+pub mod accept_authority;
+pub mod sweep;
+pub mod init_node_rewards;
+pub mod add_node_earnings;
+pub mod enter_delegation_rewards;
+pub mod claim_delegation_rewards;
+pub mod settle_delegation;
 
 pub use initialize_pool::*;
 pub use claim_rewards::*;
+pub use set_enabled::*;
+pub use set_reward_rate::*;
+pub use transfer_authority::*;
+pub use accept_authority::*;
+pub use sweep::*;
+pub use init_node_rewards::*;
+pub use add_node_earnings::*;
+pub use enter_delegation_rewards::*;
+pub use claim_delegation_rewards::*;
+pub use settle_delegation::*;
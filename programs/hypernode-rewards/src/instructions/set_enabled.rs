@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Pause or resume reward claims
+///
+/// Emergency circuit breaker: while disabled, `claim_rewards` is rejected.
+pub fn set_enabled(ctx: Context<SetEnabled>, enabled: bool) -> Result<()> {
+    let pool = &mut ctx.accounts.reward_pool;
+
+    pool.enabled = enabled;
+
+    msg!("Reward pool {}", if enabled { "enabled" } else { "disabled" });
+
+    emit!(PoolEnabledEvent {
+        authority: ctx.accounts.authority.key(),
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetEnabled<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+        has_one = authority @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct PoolEnabledEvent {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Update the share of job price routed to stakers
+///
+/// Only affects future distributions; accrued rewards are untouched.
+pub fn set_reward_rate(ctx: Context<SetRewardRate>, reward_rate_bps: u16) -> Result<()> {
+    let pool = &mut ctx.accounts.reward_pool;
+
+    require!(reward_rate_bps <= 10000, RewardError::InvalidRate);
+
+    let old_rate_bps = pool.reward_rate_bps;
+    pool.reward_rate_bps = reward_rate_bps;
+
+    msg!(
+        "Reward rate updated: {}% -> {}%",
+        old_rate_bps as f64 / 100.0,
+        reward_rate_bps as f64 / 100.0
+    );
+
+    emit!(RewardRateUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        old_rate_bps,
+        new_rate_bps: reward_rate_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRewardRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+        has_one = authority @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct RewardRateUpdatedEvent {
+    pub authority: Pubkey,
+    pub old_rate_bps: u16,
+    pub new_rate_bps: u16,
+    pub timestamp: i64,
+}
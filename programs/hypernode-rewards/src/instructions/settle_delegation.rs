@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use hypernode_staking::state::StakeAccount;
+use crate::state::*;
+
+/// Settle a delegator's node rewards and re-checkpoint its xNOS
+///
+/// Only callable by hypernode-staking via CPI (`[b"staking_authority"]`
+/// PDA signs) on every delegate/undelegate of an entered position, before
+/// the position changes. Pending rewards move into `owed` and the
+/// checkpoint becomes `xnos`: the position's xNOS when delegating to this
+/// node, 0 when undelegating.
+pub fn settle_delegation(ctx: Context<SettleDelegation>, xnos: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.node_reward_pool;
+    let stake_account = &ctx.accounts.stake_account;
+    let delegator = &mut ctx.accounts.delegator_rewards;
+
+    let current_xnos = if stake_account.delegated_to == Some(pool.node) {
+        stake_account.xnos
+    } else {
+        0
+    };
+    pool.settle(delegator, current_xnos, xnos)?;
+
+    msg!(
+        "Settled position {} in node {} rewards: {} owed, {} xNOS",
+        stake_account.position_id,
+        pool.node,
+        delegator.owed,
+        xnos
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleDelegation<'info> {
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_reward_pool.node.as_ref()],
+        bump = node_reward_pool.bump
+    )]
+    pub node_reward_pool: Account<'info, NodeRewardPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"delegator",
+            stake_account.key().as_ref(),
+            node_reward_pool.node.as_ref()
+        ],
+        bump = delegator_rewards.bump
+    )]
+    pub delegator_rewards: Account<'info, DelegatorRewards>,
+
+    /// Stake position being delegated or undelegated
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// hypernode-staking authority PDA (signs via CPI)
+    #[account(
+        seeds = [b"staking_authority"],
+        bump,
+        seeds::program = hypernode_staking::ID
+    )]
+    pub staking_authority: Signer<'info>,
+}
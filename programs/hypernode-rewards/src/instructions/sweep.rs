@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Emergency sweep of unallocated tokens from the reward vault
+///
+/// Only the surplus above `total_rewards` can be moved:
+/// - Rewards already accrued to stakers stay in the vault
+/// - Authority-only (use for stray deposits or pool migration)
+pub fn sweep(ctx: Context<Sweep>, amount: u64) -> Result<()> {
+    let pool = &ctx.accounts.reward_pool;
+
+    require!(amount > 0, RewardError::InvalidAmount);
+
+    let unallocated = pool.unallocated(ctx.accounts.reward_vault.amount);
+    require!(amount <= unallocated, RewardError::InsufficientUnallocated);
+
+    // Transfer surplus out of the vault
+    let seeds = &[b"reward_pool".as_ref(), &[pool.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reward_vault.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+    token::transfer(cpi_ctx, amount)?;
+
+    msg!(
+        "Swept {} unallocated tokens ({} remain owed to stakers)",
+        amount,
+        pool.total_rewards
+    );
+
+    emit!(SweepEvent {
+        authority: ctx.accounts.authority.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    #[account(
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+        has_one = authority @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// Reward vault (must be owned by the pool PDA)
+    #[account(
+        mut,
+        constraint = reward_vault.owner == reward_pool.key()
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Destination for swept tokens
+    #[account(
+        mut,
+        constraint = destination.mint == reward_vault.mint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct SweepEvent {
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
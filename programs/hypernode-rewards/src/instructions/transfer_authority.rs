@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Propose a new pool authority (step 1 of 2)
+///
+/// The proposed key must call `accept_authority` before it takes effect,
+/// so a typo cannot lock the pool. Proposing again overwrites the pending key.
+pub fn transfer_authority(
+    ctx: Context<TransferAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.reward_pool;
+
+    pool.pending_authority = Some(new_authority);
+
+    msg!("Authority transfer proposed: {} -> {}", pool.authority, new_authority);
+
+    emit!(AuthorityTransferProposedEvent {
+        authority: pool.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"reward_pool"],
+        bump = reward_pool.bump,
+        has_one = authority @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -43,9 +43,85 @@ pub mod hypernode_rewards {
     ///
     /// Users claim their proportional share:
     /// - No iteration needed (O(1))
-    /// - Based on current xNOS amount
+    /// - Based on current xNOS amount (decayed in vote-escrow mode)
     /// - Instant calculation and transfer
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    pub fn claim_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRewards<'info>>,
+    ) -> Result<()> {
         instructions::claim_rewards(ctx)
     }
+
+    /// Pause or resume reward claims (authority only)
+    pub fn set_enabled(ctx: Context<SetEnabled>, enabled: bool) -> Result<()> {
+        instructions::set_enabled(ctx, enabled)
+    }
+
+    /// Update reward rate (authority only)
+    ///
+    /// - reward_rate_bps: New percentage of job price (max 10000)
+    pub fn set_reward_rate(ctx: Context<SetRewardRate>, reward_rate_bps: u16) -> Result<()> {
+        instructions::set_reward_rate(ctx, reward_rate_bps)
+    }
+
+    /// Propose a new pool authority (authority only)
+    ///
+    /// Two-step transfer: takes effect once `accept_authority` is called.
+    pub fn transfer_authority(
+        ctx: Context<TransferAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending authority transfer (signed by the new authority)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    /// Emergency sweep of unallocated vault tokens (authority only)
+    ///
+    /// Cannot move rewards already accrued to stakers:
+    /// - Sweepable = vault_balance - total_rewards
+    pub fn sweep(ctx: Context<Sweep>, amount: u64) -> Result<()> {
+        instructions::sweep(ctx, amount)
+    }
+
+    /// Create a node earnings pool (node operator only)
+    ///
+    /// Node must be open for delegation in hypernode-staking.
+    pub fn init_node_rewards(ctx: Context<InitNodeRewards>) -> Result<()> {
+        instructions::init_node_rewards(ctx)
+    }
+
+    /// Deposit node earnings, split between operator and delegators
+    ///
+    /// - Operator receives its effective commission immediately
+    /// - Entered delegators share the rest by xNOS (O(1))
+    pub fn add_node_earnings(ctx: Context<AddNodeEarnings>, amount: u64) -> Result<()> {
+        instructions::add_node_earnings(ctx, amount)
+    }
+
+    /// Start earning node rewards for a delegated position
+    pub fn enter_delegation_rewards(
+        ctx: Context<EnterDelegationRewards>,
+        position_id: u64,
+    ) -> Result<()> {
+        instructions::enter_delegation_rewards(ctx, position_id)
+    }
+
+    /// Claim a delegated position's share of node earnings
+    pub fn claim_delegation_rewards(
+        ctx: Context<ClaimDelegationRewards>,
+        position_id: u64,
+    ) -> Result<()> {
+        instructions::claim_delegation_rewards(ctx, position_id)
+    }
+
+    /// Settle an entered position's node rewards (hypernode-staking CPI only)
+    ///
+    /// Called on every delegate/undelegate so rewards earned before an
+    /// undelegation stay claimable and none accrue while undelegated.
+    pub fn settle_delegation(ctx: Context<SettleDelegation>, xnos: u64) -> Result<()> {
+        instructions::settle_delegation(ctx, xnos)
+    }
 }
@@ -1,3 +1,5 @@
 pub mod reward_pool;
+pub mod node_reward_pool;
 
 pub use reward_pool::*;
+pub use node_reward_pool::*;
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::state::RewardError;
+
+/// Per-node earnings pool split between operator and delegators
+///
+/// Uses token reflection for delegators (O(1), no iteration):
+/// - Operator commission is paid out immediately on deposit
+/// - The remainder raises reward_per_xnos by rest * PRECISION / entered_xnos
+/// - Delegator reward = xNOS * (reward_per_xnos - last_reward_per_xnos) / PRECISION
+///
+/// Only positions that entered the pool share earnings; hypernode-staking
+/// settles their checkpoints on every delegate/undelegate via CPI.
+#[account]
+pub struct NodeRewardPool {
+    /// Node PDA (hypernode-nodes) this pool belongs to
+    pub node: Pubkey,
+
+    /// Token vault holding delegator rewards (PDA, pool is authority)
+    pub vault: Pubkey,
+
+    /// Accumulated rewards per entered xNOS (scaled by PRECISION)
+    pub reward_per_xnos: u128,
+
+    /// Sum of entered positions' xNOS checkpoints (accrual denominator)
+    pub entered_xnos: u64,
+
+    /// Stats
+    pub total_distributed: u64,
+    pub total_commission: u64,
+    pub total_claimed: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl NodeRewardPool {
+    pub const PRECISION: u128 = 1_000_000_000_000;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // node
+        32 + // vault
+        16 + // reward_per_xnos
+        8 + // entered_xnos
+        8 + // total_distributed
+        8 + // total_commission
+        8 + // total_claimed
+        1; // bump
+
+    /// Split an earnings amount into (commission, delegator share)
+    ///
+    /// With no entered xNOS the operator receives everything.
+    pub fn split(amount: u64, commission_bps: u16, entered_xnos: u64) -> Result<(u64, u64)> {
+        if entered_xnos == 0 {
+            return Ok((amount, 0));
+        }
+
+        let commission = (amount as u128)
+            .checked_mul(commission_bps as u128)
+            .and_then(|v| v.checked_div(10000))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(RewardError::CalculationOverflow)?;
+
+        Ok((commission, amount - commission))
+    }
+
+    /// Raise reward_per_xnos by a delegator share
+    pub fn accrue(&mut self, delegator_share: u64) -> Result<()> {
+        if delegator_share == 0 || self.entered_xnos == 0 {
+            return Ok(());
+        }
+
+        let increment = (delegator_share as u128)
+            .checked_mul(Self::PRECISION)
+            .and_then(|v| v.checked_div(self.entered_xnos as u128))
+            .ok_or(RewardError::CalculationOverflow)?;
+
+        self.reward_per_xnos = self
+            .reward_per_xnos
+            .checked_add(increment)
+            .ok_or(RewardError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Rewards owed for an xNOS amount since a checkpoint
+    pub fn pending(&self, xnos: u64, last_reward_per_xnos: u128) -> Result<u64> {
+        let delta = self.reward_per_xnos.saturating_sub(last_reward_per_xnos);
+
+        let pending = (xnos as u128)
+            .checked_mul(delta)
+            .and_then(|v| v.checked_div(Self::PRECISION))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(RewardError::CalculationOverflow)?;
+
+        Ok(pending)
+    }
+
+    /// Move a delegator's pending rewards into `owed` and re-checkpoint it
+    ///
+    /// Pending rewards use min(xNOS checkpoint, current xNOS), so a topup
+    /// never claims retroactively while slashes apply at once. The new
+    /// checkpoint replaces the old one in `entered_xnos`.
+    pub fn settle(
+        &mut self,
+        delegator: &mut DelegatorRewards,
+        current_xnos: u64,
+        new_xnos: u64,
+    ) -> Result<u64> {
+        let xnos = delegator.xnos_snapshot.min(current_xnos);
+        let pending = self.pending(xnos, delegator.last_reward_per_xnos)?;
+
+        delegator.owed = delegator
+            .owed
+            .checked_add(pending)
+            .ok_or(RewardError::CalculationOverflow)?;
+        self.entered_xnos = self
+            .entered_xnos
+            .saturating_sub(delegator.xnos_snapshot)
+            .checked_add(new_xnos)
+            .ok_or(RewardError::CalculationOverflow)?;
+        delegator.last_reward_per_xnos = self.reward_per_xnos;
+        delegator.xnos_snapshot = new_xnos;
+
+        Ok(xnos)
+    }
+}
+
+/// A delegated stake position's checkpoint in a node reward pool
+///
+/// One per (position, node): re-delegating to another node enters that
+/// node's pool separately, and rewards settled here stay claimable.
+#[account]
+pub struct DelegatorRewards {
+    /// Stake position (hypernode-staking) this checkpoint tracks
+    pub stake_account: Pubkey,
+
+    /// Node whose pool this checkpoint belongs to
+    pub node: Pubkey,
+
+    /// reward_per_xnos at last checkpoint
+    pub last_reward_per_xnos: u128,
+
+    /// Position xNOS at last checkpoint (0 while undelegated from this node)
+    pub xnos_snapshot: u64,
+
+    /// Settled rewards not yet claimed
+    pub owed: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DelegatorRewards {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // stake_account
+        32 + // node
+        16 + // last_reward_per_xnos
+        8 + // xnos_snapshot
+        8 + // owed
+        1; // bump
+}
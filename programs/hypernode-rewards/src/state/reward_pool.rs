@@ -13,6 +13,9 @@ pub struct RewardPool {
     /// Authority that can update pool parameters
     pub authority: Pubkey,
 
+    /// Proposed new authority (two-step transfer, must accept)
+    pub pending_authority: Option<Pubkey>,
+
     /// Total rewards accumulated (not yet claimed)
     pub total_rewards: u64,
 
@@ -37,6 +40,7 @@ pub struct RewardPool {
 impl RewardPool {
     pub const SPACE: usize = 8 + // discriminator
         32 + // authority
+        1 + 32 + // pending_authority (Option<Pubkey>)
         8 + // total_rewards
         8 + // total_claimed
         2 + // reward_rate_bps
@@ -67,6 +71,15 @@ impl RewardPool {
 
         Ok(claimable)
     }
+
+    /// Calculate tokens in the vault that are not owed to stakers
+    ///
+    /// Formula: vault_balance - total_rewards
+    ///
+    /// Only this surplus can be swept by the authority.
+    pub fn unallocated(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.total_rewards)
+    }
 }
 
 #[error_code]
@@ -82,4 +95,25 @@ pub enum RewardError {
 
     #[msg("User has no staked xNOS")]
     NoStake,
+
+    #[msg("Reward rate must be <= 10000 bps (100%)")]
+    InvalidRate,
+
+    #[msg("Only pool authority can perform this action")]
+    Unauthorized,
+
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    #[msg("Sweep amount exceeds unallocated vault balance")]
+    InsufficientUnallocated,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Stake position is not delegated to this node")]
+    NotDelegatedToNode,
+
+    #[msg("Mint is not the HYPER staking mint")]
+    InvalidMint,
 }
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Claim vested grant tokens to the beneficiary
+///
+/// Pays vested, unclaimed tokens that are not in a stake position.
+/// Vested tokens still staked become claimable after `withdraw`.
+pub fn claim_vested(ctx: Context<ClaimVested>, _grant_id: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let grant = &mut ctx.accounts.grant;
+    let clock = Clock::get()?;
+
+    let amount = grant.claimable(clock.unix_timestamp);
+    require!(amount > 0, StakingError::NothingToClaim);
+
+    // Transfer tokens from vault to beneficiary
+    let config_seeds = &[b"config".as_ref(), &[config.bump]];
+    let signer = &[&config_seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.beneficiary_token_account.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    grant.released = grant
+        .released
+        .checked_add(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    msg!(
+        "Claimed {} vested HYPER ({} of {} released)",
+        amount,
+        grant.released,
+        grant.total_amount
+    );
+
+    emit!(VestedClaimEvent {
+        beneficiary: grant.beneficiary,
+        grant_id: grant.grant_id,
+        amount,
+        released: grant.released,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(grant_id: u64)]
+pub struct ClaimVested<'info> {
+    /// Staking configuration (vault authority)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Vesting grant
+    #[account(
+        mut,
+        seeds = [b"grant", beneficiary.key().as_ref(), &grant_id.to_le_bytes()],
+        bump = grant.bump,
+        has_one = beneficiary @ StakingError::Unauthorized
+    )]
+    pub grant: Account<'info, VestingGrant>,
+
+    /// Grant beneficiary
+    pub beneficiary: Signer<'info>,
+
+    /// Beneficiary's token account (HYPER destination)
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary.key(),
+        constraint = beneficiary_token_account.mint == config.mint @ StakingError::InvalidMint
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    /// Vault where staked and granted tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Event emitted when vested tokens are claimed
+#[event]
+pub struct VestedClaimEvent {
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub amount: u64,
+    pub released: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Close a node's delegation account (operator only)
+///
+/// Only once every delegator has undelegated. Returns rent to the
+/// operator and lets the node be closed in hypernode-nodes.
+pub fn close_delegation(ctx: Context<CloseDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.node_delegation;
+
+    require!(
+        delegation.delegators == 0 && delegation.delegated_amount == 0,
+        StakingError::DelegationNotEmpty
+    );
+
+    msg!("Delegation closed for node {}", delegation.node);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseDelegation<'info> {
+    /// Node's delegation account
+    #[account(
+        mut,
+        seeds = [b"delegation", node_delegation.node.as_ref()],
+        bump = node_delegation.bump,
+        has_one = operator @ StakingError::UnauthorizedOperator,
+        close = operator
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Node operator
+    #[account(mut)]
+    pub operator: Signer<'info>,
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Create a vesting grant (cliff + linear) for a beneficiary
+///
+/// The funder deposits `amount` HYPER into the stake vault. The
+/// beneficiary can stake it right away and claims it as it vests.
+pub fn create_grant(
+    ctx: Context<CreateGrant>,
+    grant_id: u64,
+    beneficiary: Pubkey,
+    amount: u64,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    let grant = &mut ctx.accounts.grant;
+
+    // Validation
+    require!(amount > 0, StakingError::InvalidAmount);
+    require!(
+        VestingGrant::is_valid_schedule(start_ts, cliff_ts, end_ts),
+        StakingError::InvalidSchedule
+    );
+
+    // Transfer tokens to vault (escrow)
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    grant.beneficiary = beneficiary;
+    grant.funder = ctx.accounts.funder.key();
+    grant.grant_id = grant_id;
+    grant.total_amount = amount;
+    grant.released = 0;
+    grant.staked = 0;
+    grant.start_ts = start_ts;
+    grant.cliff_ts = cliff_ts;
+    grant.end_ts = end_ts;
+    grant.bump = ctx.bumps.grant;
+
+    msg!(
+        "Granted {} HYPER to {} (cliff {}, fully vested {})",
+        amount,
+        beneficiary,
+        cliff_ts,
+        end_ts
+    );
+
+    emit!(GrantCreatedEvent {
+        beneficiary,
+        funder: grant.funder,
+        grant_id,
+        amount,
+        start_ts,
+        cliff_ts,
+        end_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(grant_id: u64, beneficiary: Pubkey)]
+pub struct CreateGrant<'info> {
+    /// Staking configuration
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// New vesting grant (PDA)
+    #[account(
+        init,
+        payer = funder,
+        space = VestingGrant::SPACE,
+        seeds = [b"grant", beneficiary.as_ref(), &grant_id.to_le_bytes()],
+        bump
+    )]
+    pub grant: Account<'info, VestingGrant>,
+
+    /// Wallet funding the grant
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Funder's token account (HYPER)
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key(),
+        constraint = funder_token_account.mint == config.mint @ StakingError::InvalidMint
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    /// Vault where staked and granted tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a vesting grant is created
+#[event]
+pub struct GrantCreatedEvent {
+    pub beneficiary: Pubkey,
+    pub funder: Pubkey,
+    pub grant_id: u64,
+    pub amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Delegate a stake position to a node
+///
+/// The position's amount and xNOS are added to the node's delegation:
+/// - Counts toward the node's market eligibility
+/// - Shares the node's slashing risk
+/// - Earns node earnings minus operator commission (rewards program)
+///
+/// The holder keeps ownership; tokens stay in the stake vault. If the
+/// position entered this node's reward pool before, it is re-checkpointed.
+pub fn delegate(ctx: Context<Delegate>, _position_id: u64) -> Result<()> {
+    let delegation = &mut ctx.accounts.node_delegation;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: active, undelegated position
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(!stake_account.is_unbonding(), StakingError::AlreadyUnbonding);
+    require!(
+        stake_account.delegated_to.is_none(),
+        StakingError::AlreadyDelegated
+    );
+
+    // Settle node rewards before the delegated xNOS changes
+    settle_node_rewards(
+        &ctx.accounts.node_reward_pool.to_account_info(),
+        &ctx.accounts.delegator_rewards.to_account_info(),
+        &stake_account.to_account_info(),
+        &ctx.accounts.staking_authority.to_account_info(),
+        &ctx.accounts.rewards_program.to_account_info(),
+        ctx.bumps.staking_authority,
+        stake_account.xnos,
+    )?;
+
+    ctx.accounts.staker.rebalance_delegated(0, stake_account.xnos)?;
+    delegation.rebalance(0, 0, stake_account.staked_amount, stake_account.xnos)?;
+    delegation.delegators = delegation
+        .delegators
+        .checked_add(1)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    stake_account.delegated_to = Some(delegation.node);
+
+    msg!(
+        "Delegated position {} ({} xNOS) to node {}",
+        stake_account.position_id,
+        stake_account.xnos,
+        delegation.node
+    );
+
+    emit!(DelegateEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        node: delegation.node,
+        amount: stake_account.staked_amount,
+        xnos: stake_account.xnos,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Delegate<'info> {
+    /// Delegation account of the target node
+    #[account(
+        mut,
+        seeds = [b"delegation", node_delegation.node.as_ref()],
+        bump = node_delegation.bump
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Owner's wallet aggregate (delegated xNOS leaves its own stake)
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being delegated
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (settled via CPI)
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_delegation.node.as_ref()],
+        bump,
+        seeds::program = rewards_program::ID
+    )]
+    pub node_reward_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Position's checkpoint PDA in that pool; settled if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"delegator",
+            stake_account.key().as_ref(),
+            node_delegation.node.as_ref()
+        ],
+        bump,
+        seeds::program = rewards_program::ID
+    )]
+    pub delegator_rewards: UncheckedAccount<'info>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// Position owner
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when a position is delegated
+#[event]
+pub struct DelegateEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub node: Pubkey,
+    pub amount: u64,
+    pub xnos: u64,
+    pub timestamp: i64,
+}
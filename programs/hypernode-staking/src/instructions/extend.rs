@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Extend the lock period of an existing stake
+///
+/// Longer locks earn a higher multiplier:
+/// - stake_duration += additional_seconds (capped at max_duration)
+/// - unlock_at moves forward by the same amount (from now if already unlocked)
+/// - xNOS, multiplier and tier are recomputed
+pub fn extend(ctx: Context<Extend>, _position_id: u64, additional_seconds: i64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: staking must be enabled
+    require!(config.staking_enabled, StakingError::StakingDisabled);
+
+    // Validation: stake still active
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(!stake_account.is_unbonding(), StakingError::AlreadyUnbonding);
+
+    // Validation: duration
+    require!(additional_seconds > 0, StakingError::InvalidAmount);
+    let new_duration = stake_account
+        .stake_duration
+        .checked_add(additional_seconds)
+        .ok_or(StakingError::CalculationOverflow)?;
+    require!(
+        new_duration <= config.max_duration,
+        StakingError::DurationTooLong
+    );
+
+    // Recalculate xNOS with the longer duration
+    let (xnos, multiplier_bps) = StakeAccount::calculate_xnos(
+        stake_account.staked_amount,
+        new_duration,
+        &config.multiplier_curve,
+    )?;
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Update global stats (xNOS by delta to keep reward shares consistent)
+    config.total_xnos = config
+        .total_xnos
+        .checked_sub(stake_account.xnos)
+        .and_then(|v| v.checked_add(xnos))
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update node delegation (delegated positions share node totals)
+    sync_delegation(
+        ctx.accounts.node_delegation.as_mut(),
+        NodeRewardsAccounts {
+            node_reward_pool: ctx.accounts.node_reward_pool.as_ref().map(|a| a.as_ref()),
+            delegator_rewards: ctx.accounts.delegator_rewards.as_ref().map(|a| a.as_ref()),
+            staking_authority: ctx.accounts.staking_authority.as_ref(),
+            rewards_program: ctx.accounts.rewards_program.as_ref(),
+            staking_authority_bump: ctx.bumps.staking_authority,
+        },
+        staker,
+        stake_account,
+        stake_account.staked_amount,
+        xnos,
+    )?;
+
+    // Update wallet aggregate
+    staker.debit(0, stake_account.xnos, &config.tier_thresholds)?;
+    staker.credit(0, xnos, &config.tier_thresholds)?;
+
+    // Update stake account
+    stake_account.checkpoint_xnos(clock.unix_timestamp);
+    stake_account.stake_duration = new_duration;
+    stake_account.unlock_at = stake_account
+        .unlock_at
+        .max(clock.unix_timestamp)
+        .checked_add(additional_seconds)
+        .ok_or(StakingError::CalculationOverflow)?;
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+
+    msg!(
+        "Extended stake by {} seconds. New duration: {}. xNOS: {} ({}x multiplier). Tier: {:?}",
+        additional_seconds,
+        new_duration,
+        xnos,
+        multiplier_bps as f64 / 100.0,
+        tier
+    );
+
+    emit!(ExtendEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        additional_seconds,
+        stake_duration: new_duration,
+        xnos,
+        multiplier_bps,
+        tier,
+        unlock_at: stake_account.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Extend<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being modified
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Node delegation (required if the position is delegated)
+    #[account(mut)]
+    pub node_delegation: Option<Account<'info, NodeDelegation>>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (required if the
+    /// position is delegated; checked against its node)
+    #[account(mut)]
+    pub node_reward_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Position's checkpoint PDA in that pool (required if delegated)
+    #[account(mut)]
+    pub delegator_rewards: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// User extending the lock
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when a stake lock is extended
+#[event]
+pub struct ExtendEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub additional_seconds: i64,
+    pub stake_duration: i64,
+    pub xnos: u64,
+    pub multiplier_bps: u16,
+    pub tier: StakeTier,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Get a wallet's effective xNOS at a timestamp (view)
+///
+/// Sums the effective xNOS of every active position of the staker:
+/// - Static xNOS by default
+/// - Linearly decaying xNOS when config.xnos_decay_enabled (vote-escrow)
+///
+/// All non-withdrawn positions must be passed as remaining accounts so the
+/// result cannot be understated. Returned via return data for CPI callers
+/// (rewards, governance).
+pub fn get_voting_power<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetVotingPower<'info>>,
+    at_timestamp: i64,
+) -> Result<u64> {
+    let config = &ctx.accounts.config;
+    let staker = &ctx.accounts.staker;
+
+    let voting_power =
+        staker.voting_power(config, ctx.remaining_accounts, at_timestamp)?;
+
+    msg!(
+        "Voting power of {} at {}: {} xNOS",
+        staker.authority,
+        at_timestamp,
+        voting_power
+    );
+
+    Ok(voting_power)
+}
+
+#[derive(Accounts)]
+pub struct GetVotingPower<'info> {
+    /// Staking configuration (decay mode)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate whose positions are summed
+    #[account(
+        seeds = [b"staker", staker.authority.as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+}
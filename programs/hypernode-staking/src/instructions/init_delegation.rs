@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use hypernode_nodes::state::Node;
+use crate::state::*;
+
+/// Open a node for delegation (operator only)
+///
+/// Creates the node's `NodeDelegation` with the operator's commission.
+/// Holders can then delegate stake positions to this node.
+pub fn init_delegation(ctx: Context<InitDelegation>, commission_bps: u16) -> Result<()> {
+    let delegation = &mut ctx.accounts.node_delegation;
+
+    require!(commission_bps <= 10000, StakingError::InvalidCommission);
+
+    delegation.node = ctx.accounts.node.key();
+    delegation.operator = ctx.accounts.operator.key();
+    delegation.commission_bps = commission_bps;
+    delegation.pending_commission_bps = None;
+    delegation.commission_effective_at = 0;
+    delegation.delegated_amount = 0;
+    delegation.delegated_xnos = 0;
+    delegation.delegators = 0;
+    delegation.bump = ctx.bumps.node_delegation;
+
+    msg!(
+        "Node {} open for delegation ({}% commission)",
+        delegation.node,
+        commission_bps as f64 / 100.0
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitDelegation<'info> {
+    /// Delegation account for the node (PDA)
+    #[account(
+        init,
+        payer = operator,
+        space = NodeDelegation::SPACE,
+        seeds = [b"delegation", node.key().as_ref()],
+        bump
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Node from hypernode-nodes program
+    #[account(
+        constraint = node.authority == operator.key() @ StakingError::UnauthorizedOperator
+    )]
+    pub node: Account<'info, Node>,
+
+    /// Node operator
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
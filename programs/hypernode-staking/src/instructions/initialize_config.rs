@@ -1,33 +1,45 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 
 /// Initialize staking configuration
 ///
-/// Creates the global StakingConfig account with default parameters.
+/// Creates the global StakingConfig account with default parameters,
+/// and the stake vault as a PDA token account owned by the config.
 /// Only needs to be called once during program deployment.
 pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
-    let (min_stake, max_stake, min_duration, max_duration, tier_thresholds) =
+    let (min_stake, max_stake, min_duration, max_duration, tier_thresholds, multiplier_curve) =
         StakingConfig::default_config();
 
     config.authority = ctx.accounts.authority.key();
+    config.slasher = ctx.accounts.authority.key();
+    config.slash_treasury = None;
+    config.mint = ctx.accounts.mint.key();
+    config.vault = ctx.accounts.vault.key();
     config.min_stake_amount = min_stake;
     config.max_stake_amount = max_stake;
     config.min_duration = min_duration;
     config.max_duration = max_duration;
+    config.unbonding_period = StakingConfig::DEFAULT_UNBONDING_PERIOD;
     config.tier_thresholds = tier_thresholds;
+    config.multiplier_curve = multiplier_curve;
     config.staking_enabled = true;
+    config.xnos_decay_enabled = false;
     config.total_staked = 0;
     config.total_xnos = 0;
     config.total_stakers = 0;
     config.bump = ctx.bumps.config;
 
     msg!("Staking config initialized");
+    msg!("Mint: {}", config.mint);
+    msg!("Vault: {}", config.vault);
     msg!("Min stake: {} lamports", min_stake);
     msg!("Max stake: {} lamports", max_stake);
     msg!("Min duration: {} seconds", min_duration);
     msg!("Max duration: {} seconds", max_duration);
+    msg!("Unbonding period: {} seconds", config.unbonding_period);
 
     Ok(())
 }
@@ -44,10 +56,30 @@ pub struct InitializeConfig<'info> {
     )]
     pub config: Account<'info, StakingConfig>,
 
+    /// Vault holding all staked tokens (PDA, config is token authority)
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = config,
+        seeds = [b"vault"],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// HYPER token mint (pinned for all stakes)
+    pub mint: Account<'info, Mint>,
+
     /// Authority initializing config (becomes config authority)
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
 }
@@ -1,7 +1,41 @@
 pub mod initialize_config;
 pub mod stake;
-pub mod unstake;
+pub mod request_unstake;
+pub mod withdraw;
+pub mod restake;
+pub mod topup;
+pub mod extend;
+pub mod set_slasher;
+pub mod slash;
+pub mod update_config;
+pub mod recalculate;
+pub mod get_voting_power;
+pub mod init_delegation;
+pub mod set_commission;
+pub mod delegate;
+pub mod undelegate;
+pub mod close_delegation;
+pub mod create_grant;
+pub mod stake_grant;
+pub mod claim_vested;
 
 pub use initialize_config::*;
 pub use stake::*;
-pub use unstake::*;
+pub use request_unstake::*;
+pub use withdraw::*;
+pub use restake::*;
+pub use topup::*;
+pub use extend::*;
+pub use set_slasher::*;
+pub use slash::*;
+pub use update_config::*;
+pub use recalculate::*;
+pub use get_voting_power::*;
+pub use init_delegation::*;
+pub use set_commission::*;
+pub use delegate::*;
+pub use undelegate::*;
+pub use close_delegation::*;
+pub use create_grant::*;
+pub use stake_grant::*;
+pub use claim_vested::*;
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Refresh a position's xNOS, multiplier and tier from current config
+///
+/// Permissionless crank: anyone can apply a config change to any position.
+/// Unbonding and withdrawn positions carry no xNOS and are left unchanged.
+pub fn recalculate(ctx: Context<Recalculate>, _position_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    // Validation: position must hold xNOS
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(!stake_account.is_unbonding(), StakingError::AlreadyUnbonding);
+
+    let (xnos, multiplier_bps) = StakeAccount::calculate_xnos(
+        stake_account.staked_amount,
+        stake_account.stake_duration,
+        &config.multiplier_curve,
+    )?;
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Update global stats
+    config.total_xnos = config
+        .total_xnos
+        .checked_sub(stake_account.xnos)
+        .and_then(|v| v.checked_add(xnos))
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update node delegation (delegated positions share node totals)
+    sync_delegation(
+        ctx.accounts.node_delegation.as_mut(),
+        NodeRewardsAccounts {
+            node_reward_pool: ctx.accounts.node_reward_pool.as_ref().map(|a| a.as_ref()),
+            delegator_rewards: ctx.accounts.delegator_rewards.as_ref().map(|a| a.as_ref()),
+            staking_authority: ctx.accounts.staking_authority.as_ref(),
+            rewards_program: ctx.accounts.rewards_program.as_ref(),
+            staking_authority_bump: ctx.bumps.staking_authority,
+        },
+        staker,
+        stake_account,
+        stake_account.staked_amount,
+        xnos,
+    )?;
+
+    // Update wallet aggregate (also refreshes aggregate tier)
+    staker.debit(0, stake_account.xnos, &config.tier_thresholds)?;
+    staker.credit(0, xnos, &config.tier_thresholds)?;
+
+    stake_account.checkpoint_xnos(Clock::get()?.unix_timestamp);
+    let old_xnos = stake_account.xnos;
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+
+    msg!(
+        "Recalculated position {} of {}: {} -> {} xNOS. Tier: {:?}",
+        stake_account.position_id,
+        stake_account.authority,
+        old_xnos,
+        xnos,
+        tier
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Recalculate<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Aggregate of the position owner
+    #[account(
+        mut,
+        seeds = [b"staker", stake_account.authority.as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position to refresh
+    #[account(
+        mut,
+        seeds = [b"stake", stake_account.authority.as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Node delegation (required if the position is delegated)
+    #[account(mut)]
+    pub node_delegation: Option<Account<'info, NodeDelegation>>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (required if the
+    /// position is delegated; checked against its node)
+    #[account(mut)]
+    pub node_reward_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Position's checkpoint PDA in that pool (required if delegated)
+    #[account(mut)]
+    pub delegator_rewards: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// Anyone can crank (permissionless)
+    pub caller: Signer<'info>,
+}
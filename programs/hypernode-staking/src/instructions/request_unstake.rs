@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Request unstake of a position after its lock period ends (phase 1 of 2)
+///
+/// Starts the unbonding cooldown:
+/// - xNOS is burned immediately (voting power and rewards stop)
+/// - Tokens stay in the vault and remain slashable
+/// - `withdraw` is allowed once config.unbonding_period has passed
+/// - `restake` cancels the request
+pub fn request_unstake(ctx: Context<RequestUnstake>, _position_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: lock period must have ended
+    require!(
+        clock.unix_timestamp >= stake_account.unlock_at,
+        StakingError::StakeLocked
+    );
+
+    // Validation: not already withdrawn or unbonding
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(!stake_account.is_unbonding(), StakingError::AlreadyUnbonding);
+
+    // Validation: delegated stake must be undelegated first
+    require!(
+        stake_account.delegated_to.is_none(),
+        StakingError::PositionDelegated
+    );
+
+    let xnos_burned = stake_account.xnos;
+    let withdrawable_at = clock
+        .unix_timestamp
+        .checked_add(config.unbonding_period)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update global stats (tokens stay staked until withdraw)
+    config.total_xnos = config
+        .total_xnos
+        .checked_sub(xnos_burned)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update wallet aggregate
+    staker.debit(0, xnos_burned, &config.tier_thresholds)?;
+
+    // Burn xNOS and start unbonding
+    stake_account.checkpoint_xnos(clock.unix_timestamp);
+    stake_account.xnos = 0;
+    stake_account.tier = StakeAccount::calculate_tier(0, &config.tier_thresholds);
+    stake_account.withdrawable_at = Some(withdrawable_at);
+
+    msg!(
+        "Unstake requested. Burned {} xNOS. Withdrawable at {}.",
+        xnos_burned,
+        withdrawable_at
+    );
+
+    // Emit event
+    emit!(UnstakeEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        amount: stake_account.staked_amount,
+        xnos_burned,
+        withdrawable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct RequestUnstake<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being unstaked
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Owner requesting unstake
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when an unstake is requested
+#[event]
+pub struct UnstakeEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub xnos_burned: u64,
+    pub withdrawable_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Cancel a pending unstake and restake the position
+///
+/// Restores xNOS from the (possibly slashed) staked amount and
+/// relocks the position for its full stake duration.
+pub fn restake(ctx: Context<Restake>, _position_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: staking must be enabled
+    require!(config.staking_enabled, StakingError::StakingDisabled);
+
+    // Validation: position must be unbonding
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(stake_account.is_unbonding(), StakingError::NotUnbonding);
+
+    // Recalculate xNOS
+    let (xnos, multiplier_bps) = StakeAccount::calculate_xnos(
+        stake_account.staked_amount,
+        stake_account.stake_duration,
+        &config.multiplier_curve,
+    )?;
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Update global stats
+    config.total_xnos = config
+        .total_xnos
+        .checked_add(xnos)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update wallet aggregate
+    staker.credit(0, xnos, &config.tier_thresholds)?;
+
+    // Relock position
+    stake_account.checkpoint_xnos(clock.unix_timestamp);
+    stake_account.unlock_at = clock
+        .unix_timestamp
+        .checked_add(stake_account.stake_duration)
+        .ok_or(StakingError::CalculationOverflow)?;
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+    stake_account.withdrawable_at = None;
+
+    msg!(
+        "Restaked {} HYPER. Restored {} xNOS. Tier: {:?}",
+        stake_account.staked_amount,
+        xnos,
+        tier
+    );
+
+    emit!(RestakeEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        amount: stake_account.staked_amount,
+        xnos,
+        tier,
+        unlock_at: stake_account.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Restake<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being restaked
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Owner cancelling the unstake
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when an unstake request is cancelled
+#[event]
+pub struct RestakeEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub xnos: u64,
+    pub tier: StakeTier,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
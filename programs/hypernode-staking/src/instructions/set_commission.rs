@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Schedule a new operator commission on node earnings (operator only)
+///
+/// Takes effect one unbonding period from now, so delegators can undelegate
+/// and withdraw before a raise applies. Replaces any change still pending.
+pub fn set_commission(ctx: Context<SetCommission>, commission_bps: u16) -> Result<()> {
+    let delegation = &mut ctx.accounts.node_delegation;
+    let clock = Clock::get()?;
+
+    require!(commission_bps <= 10000, StakingError::InvalidCommission);
+
+    delegation.apply_pending_commission(clock.unix_timestamp);
+
+    let effective_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.config.unbonding_period)
+        .ok_or(StakingError::CalculationOverflow)?;
+    let old_commission_bps = delegation.commission_bps;
+    delegation.pending_commission_bps = Some(commission_bps);
+    delegation.commission_effective_at = effective_at;
+
+    msg!(
+        "Node {} commission: {}% -> {}% at {}",
+        delegation.node,
+        old_commission_bps as f64 / 100.0,
+        commission_bps as f64 / 100.0,
+        effective_at
+    );
+
+    emit!(CommissionUpdatedEvent {
+        node: delegation.node,
+        old_commission_bps,
+        new_commission_bps: commission_bps,
+        effective_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCommission<'info> {
+    /// Node's delegation account
+    #[account(
+        mut,
+        seeds = [b"delegation", node_delegation.node.as_ref()],
+        bump = node_delegation.bump,
+        has_one = operator @ StakingError::UnauthorizedOperator
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Staking configuration (unbonding period)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Node operator
+    pub operator: Signer<'info>,
+}
+
+/// Event emitted when a node's commission change is scheduled
+#[event]
+pub struct CommissionUpdatedEvent {
+    pub node: Pubkey,
+    pub old_commission_bps: u16,
+    pub new_commission_bps: u16,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
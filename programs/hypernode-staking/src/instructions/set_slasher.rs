@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Configure who can slash and where slashed tokens go
+///
+/// - slasher: Signer key or program PDA allowed to call `slash`
+/// - treasury: Token account receiving slashed HYPER (None = burn)
+pub fn set_slasher(
+    ctx: Context<SetSlasher>,
+    slasher: Pubkey,
+    treasury: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.slasher = slasher;
+    config.slash_treasury = treasury;
+
+    msg!("Slasher set to {}", slasher);
+    match treasury {
+        Some(treasury) => msg!("Slashed tokens go to treasury {}", treasury),
+        None => msg!("Slashed tokens are burned"),
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSlasher<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Config authority
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Slash a stake position for misbehaviour
+///
+/// Removes `amount_bps` of the position's staked HYPER:
+/// - Sent to config.slash_treasury, or burned if none is set
+/// - staked_amount, xNOS and tier are recomputed
+/// - Allowed while locked and while unbonding (xNOS stays zero)
+///
+/// Only callable by config.slasher (admin key or program PDA via CPI).
+pub fn slash(
+    ctx: Context<Slash>,
+    _position_id: u64,
+    amount_bps: u16,
+    reason: String,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: slash parameters
+    require!(
+        amount_bps > 0 && amount_bps <= 10000,
+        StakingError::InvalidSlashBps
+    );
+    require!(
+        reason.len() <= StakeAccount::MAX_SLASH_REASON_LEN,
+        StakingError::SlashReasonTooLong
+    );
+
+    // Validation: tokens must still be in the vault
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+
+    // Calculate slashed amount
+    let slashed = (stake_account.staked_amount as u128)
+        .checked_mul(amount_bps as u128)
+        .and_then(|v| v.checked_div(10000))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(StakingError::CalculationOverflow)?;
+    require!(slashed > 0, StakingError::InvalidAmount);
+
+    let new_amount = stake_account
+        .staked_amount
+        .checked_sub(slashed)
+        .ok_or(StakingError::CalculationOverflow)?;
+    let (xnos, multiplier_bps) = if stake_account.is_unbonding() {
+        (0, stake_account.multiplier_bps)
+    } else {
+        StakeAccount::calculate_xnos(
+            new_amount,
+            stake_account.stake_duration,
+            &config.multiplier_curve,
+        )?
+    };
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Move slashed tokens out of the vault (config PDA is vault authority)
+    let config_seeds = &[b"config".as_ref(), &[config.bump]];
+    let signer = &[&config_seeds[..]];
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    match config.slash_treasury {
+        Some(treasury_key) => {
+            let treasury = ctx
+                .accounts
+                .treasury
+                .as_ref()
+                .ok_or(StakingError::InvalidTreasury)?;
+            require!(treasury.key() == treasury_key, StakingError::InvalidTreasury);
+            require!(treasury.mint == config.mint, StakingError::InvalidMint);
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: treasury.to_account_info(),
+                authority: config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, slashed)?;
+        }
+        None => {
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.vault.to_account_info(),
+                authority: config.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::burn(cpi_ctx, slashed)?;
+        }
+    }
+
+    // Update global stats
+    config.total_staked = config
+        .total_staked
+        .checked_sub(slashed)
+        .ok_or(StakingError::CalculationOverflow)?;
+    config.total_xnos = config
+        .total_xnos
+        .checked_sub(stake_account.xnos)
+        .and_then(|v| v.checked_add(xnos))
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update node delegation (delegated positions share node totals)
+    sync_delegation(
+        ctx.accounts.node_delegation.as_mut(),
+        NodeRewardsAccounts {
+            node_reward_pool: ctx.accounts.node_reward_pool.as_ref().map(|a| a.as_ref()),
+            delegator_rewards: ctx.accounts.delegator_rewards.as_ref().map(|a| a.as_ref()),
+            staking_authority: ctx.accounts.staking_authority.as_ref(),
+            rewards_program: ctx.accounts.rewards_program.as_ref(),
+            staking_authority_bump: ctx.bumps.staking_authority,
+        },
+        staker,
+        stake_account,
+        new_amount,
+        xnos,
+    )?;
+
+    // Update vesting grant (grant-funded positions lose granted tokens)
+    sync_grant(ctx.accounts.grant.as_mut(), stake_account, new_amount)?;
+
+    // Update wallet aggregate
+    staker.debit(slashed, stake_account.xnos, &config.tier_thresholds)?;
+    staker.credit(0, xnos, &config.tier_thresholds)?;
+
+    // Update stake account
+    stake_account.staked_amount = new_amount;
+    stake_account.checkpoint_xnos(clock.unix_timestamp);
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+
+    msg!(
+        "Slashed {} HYPER ({} bps) from {} position {}: {}",
+        slashed,
+        amount_bps,
+        stake_account.authority,
+        stake_account.position_id,
+        reason
+    );
+
+    emit!(SlashEvent {
+        user: stake_account.authority,
+        position_id: stake_account.position_id,
+        slasher: ctx.accounts.slasher.key(),
+        amount: slashed,
+        amount_bps,
+        burned: config.slash_treasury.is_none(),
+        staked_amount: new_amount,
+        xnos,
+        tier,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Slash<'info> {
+    /// Staking configuration (vault authority)
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = slasher @ StakingError::UnauthorizedSlasher
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Aggregate of the slashed wallet
+    #[account(
+        mut,
+        seeds = [b"staker", stake_account.authority.as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being slashed
+    #[account(
+        mut,
+        seeds = [b"stake", stake_account.authority.as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Node delegation (required if the position is delegated)
+    #[account(mut)]
+    pub node_delegation: Option<Account<'info, NodeDelegation>>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (required if the
+    /// position is delegated; checked against its node)
+    #[account(mut)]
+    pub node_reward_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Position's checkpoint PDA in that pool (required if delegated)
+    #[account(mut)]
+    pub delegator_rewards: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// Vesting grant (required if the position is grant-funded)
+    #[account(mut)]
+    pub grant: Option<Account<'info, VestingGrant>>,
+
+    /// Vault where staked tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// HYPER mint (burned from when no treasury is configured)
+    #[account(
+        mut,
+        address = config.mint @ StakingError::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Treasury token account (required when config.slash_treasury is set)
+    #[account(mut)]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+
+    /// Configured slasher (admin key or program PDA)
+    pub slasher: Signer<'info>,
+
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Event emitted when a stake is slashed
+#[event]
+pub struct SlashEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub slasher: Pubkey,
+    pub amount: u64,
+    pub amount_bps: u16,
+    pub burned: bool,
+    pub staked_amount: u64,
+    pub xnos: u64,
+    pub tier: StakeTier,
+    pub reason: String,
+    pub timestamp: i64,
+}
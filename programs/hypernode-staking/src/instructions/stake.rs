@@ -9,15 +9,26 @@ use crate::state::*;
 /// - Duration of lock (longer = higher multiplier)
 ///
 /// xNOS represents voting power and priority in the network
+///
+/// Each call opens a new position; `position_id` must equal the wallet's
+/// `Staker.next_position_id` (0 for the first stake).
 pub fn stake(
     ctx: Context<Stake>,
     amount: u64,
     duration_seconds: i64,
+    position_id: u64,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
     let stake_account = &mut ctx.accounts.stake_account;
     let clock = Clock::get()?;
 
+    // Validation: positions are opened sequentially
+    require!(
+        position_id == staker.next_position_id,
+        StakingError::InvalidPositionId
+    );
+
     // Validation: staking must be enabled
     require!(config.staking_enabled, StakingError::StakingDisabled);
 
@@ -43,10 +54,11 @@ pub fn stake(
     );
 
     // Calculate xNOS and multiplier
-    let (xnos, multiplier_bps) = StakeAccount::calculate_xnos(amount, duration_seconds)?;
+    let (xnos, multiplier_bps) =
+        StakeAccount::calculate_xnos(amount, duration_seconds, &config.multiplier_curve)?;
 
     // Determine tier
-    let tier = StakeAccount::calculate_tier(xnos);
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
 
     // Transfer tokens to vault (escrow)
     let cpi_accounts = Transfer {
@@ -60,6 +72,7 @@ pub fn stake(
 
     // Initialize stake account
     stake_account.authority = ctx.accounts.authority.key();
+    stake_account.position_id = position_id;
     stake_account.staked_amount = amount;
     stake_account.stake_duration = duration_seconds;
     stake_account.staked_at = clock.unix_timestamp;
@@ -70,7 +83,12 @@ pub fn stake(
     stake_account.xnos = xnos;
     stake_account.multiplier_bps = multiplier_bps;
     stake_account.tier = tier;
+    stake_account.withdrawable_at = None;
+    stake_account.delegated_to = None;
+    stake_account.grant = None;
     stake_account.withdrawn = false;
+    stake_account.xnos_updated_at = clock.unix_timestamp;
+    stake_account.xnos_history = Default::default();
     stake_account.bump = ctx.bumps.stake_account;
 
     // Update global stats
@@ -82,7 +100,21 @@ pub fn stake(
         .total_xnos
         .checked_add(xnos)
         .ok_or(StakingError::CalculationOverflow)?;
-    config.total_stakers = config.total_stakers.checked_add(1).unwrap();
+    if staker.active_positions == 0 {
+        config.total_stakers = config.total_stakers.checked_add(1).unwrap();
+    }
+
+    // Update wallet aggregate
+    staker.authority = ctx.accounts.authority.key();
+    staker.credit(amount, xnos, &config.tier_thresholds)?;
+    staker.active_positions = staker
+        .active_positions
+        .checked_add(1)
+        .ok_or(StakingError::CalculationOverflow)?;
+    staker.next_position_id = position_id
+        .checked_add(1)
+        .ok_or(StakingError::CalculationOverflow)?;
+    staker.bump = ctx.bumps.staker;
 
     msg!(
         "Staked {} HYPER for {} seconds. Earned {} xNOS ({}x multiplier). Tier: {:?}",
@@ -96,6 +128,7 @@ pub fn stake(
     // Emit event
     emit!(StakeEvent {
         user: ctx.accounts.authority.key(),
+        position_id,
         amount,
         duration_seconds,
         xnos,
@@ -109,20 +142,32 @@ pub fn stake(
 }
 
 #[derive(Accounts)]
+#[instruction(amount: u64, duration_seconds: i64, position_id: u64)]
 pub struct Stake<'info> {
     /// Staking configuration
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump
     )]
     pub config: Account<'info, StakingConfig>,
 
-    /// User's stake account (PDA)
+    /// Wallet aggregate across positions (created on first stake)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Staker::SPACE,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// New stake position (PDA)
     #[account(
         init,
         payer = authority,
         space = StakeAccount::SPACE,
-        seeds = [b"stake", authority.key().as_ref()],
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
         bump
     )]
     pub stake_account: Account<'info, StakeAccount>,
@@ -134,12 +179,16 @@ pub struct Stake<'info> {
     /// User's token account (HYPER)
     #[account(
         mut,
-        constraint = user_token_account.owner == authority.key()
+        constraint = user_token_account.owner == authority.key(),
+        constraint = user_token_account.mint == config.mint @ StakingError::InvalidMint
     )]
     pub user_token_account: Account<'info, TokenAccount>,
 
-    /// Vault where staked tokens are held
-    #[account(mut)]
+    /// Vault where staked tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
     pub vault: Account<'info, TokenAccount>,
 
     /// SPL Token program
@@ -153,6 +202,7 @@ pub struct Stake<'info> {
 #[event]
 pub struct StakeEvent {
     pub user: Pubkey,
+    pub position_id: u64,
     pub amount: u64,
     pub duration_seconds: i64,
     pub xnos: u64,
@@ -161,33 +211,3 @@ pub struct StakeEvent {
     pub unlock_at: i64,
     pub timestamp: i64,
 }
-
-#[error_code]
-pub enum StakingError {
-    #[msg("Calculation resulted in overflow")]
-    CalculationOverflow,
-
-    #[msg("Stake is still locked (unlock_at not reached)")]
-    StakeLocked,
-
-    #[msg("Stake has already been withdrawn")]
-    AlreadyWithdrawn,
-
-    #[msg("Duration must be at least 1 day")]
-    DurationTooShort,
-
-    #[msg("Duration exceeds maximum (2 years)")]
-    DurationTooLong,
-
-    #[msg("Amount must be greater than zero")]
-    InvalidAmount,
-
-    #[msg("Amount is below minimum stake")]
-    BelowMinimum,
-
-    #[msg("Amount exceeds maximum stake per account")]
-    AboveMaximum,
-
-    #[msg("Staking is currently disabled")]
-    StakingDisabled,
-}
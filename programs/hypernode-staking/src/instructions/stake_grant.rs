@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use crate::instructions::StakeEvent;
+use crate::state::*;
+
+/// Stake locked vesting-grant tokens to earn xNOS
+///
+/// Opens a regular position funded from the grant's unstaked balance
+/// (tokens are already in the vault). Vesting keeps running while staked;
+/// on `withdraw` the tokens return to the grant, not the wallet.
+///
+/// `position_id` must equal the wallet's `Staker.next_position_id`.
+pub fn stake_grant(
+    ctx: Context<StakeGrant>,
+    _grant_id: u64,
+    amount: u64,
+    duration_seconds: i64,
+    position_id: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let grant = &mut ctx.accounts.grant;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: positions are opened sequentially
+    require!(
+        position_id == staker.next_position_id,
+        StakingError::InvalidPositionId
+    );
+
+    // Validation: staking must be enabled
+    require!(config.staking_enabled, StakingError::StakingDisabled);
+
+    // Validation: amount
+    require!(amount > 0, StakingError::InvalidAmount);
+    require!(
+        amount <= grant.unstaked(),
+        StakingError::InsufficientGrantBalance
+    );
+    require!(
+        amount >= config.min_stake_amount,
+        StakingError::BelowMinimum
+    );
+    require!(
+        amount <= config.max_stake_amount,
+        StakingError::AboveMaximum
+    );
+
+    // Validation: duration
+    require!(
+        duration_seconds >= config.min_duration,
+        StakingError::DurationTooShort
+    );
+    require!(
+        duration_seconds <= config.max_duration,
+        StakingError::DurationTooLong
+    );
+
+    // Calculate xNOS, multiplier and tier
+    let (xnos, multiplier_bps) =
+        StakeAccount::calculate_xnos(amount, duration_seconds, &config.multiplier_curve)?;
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Move tokens from the grant's unstaked balance into the position
+    grant.staked = grant
+        .staked
+        .checked_add(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Initialize stake account
+    stake_account.authority = ctx.accounts.beneficiary.key();
+    stake_account.position_id = position_id;
+    stake_account.staked_amount = amount;
+    stake_account.stake_duration = duration_seconds;
+    stake_account.staked_at = clock.unix_timestamp;
+    stake_account.unlock_at = clock
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(StakingError::CalculationOverflow)?;
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+    stake_account.withdrawable_at = None;
+    stake_account.delegated_to = None;
+    stake_account.grant = Some(grant.key());
+    stake_account.withdrawn = false;
+    stake_account.xnos_updated_at = clock.unix_timestamp;
+    stake_account.xnos_history = Default::default();
+    stake_account.bump = ctx.bumps.stake_account;
+
+    // Update global stats
+    config.total_staked = config
+        .total_staked
+        .checked_add(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+    config.total_xnos = config
+        .total_xnos
+        .checked_add(xnos)
+        .ok_or(StakingError::CalculationOverflow)?;
+    if staker.active_positions == 0 {
+        config.total_stakers = config.total_stakers.checked_add(1).unwrap();
+    }
+
+    // Update wallet aggregate
+    staker.authority = ctx.accounts.beneficiary.key();
+    staker.credit(amount, xnos, &config.tier_thresholds)?;
+    staker.active_positions = staker
+        .active_positions
+        .checked_add(1)
+        .ok_or(StakingError::CalculationOverflow)?;
+    staker.next_position_id = position_id
+        .checked_add(1)
+        .ok_or(StakingError::CalculationOverflow)?;
+    staker.bump = ctx.bumps.staker;
+
+    msg!(
+        "Staked {} granted HYPER for {} seconds. Earned {} xNOS. Tier: {:?}",
+        amount,
+        duration_seconds,
+        xnos,
+        tier
+    );
+
+    emit!(StakeEvent {
+        user: ctx.accounts.beneficiary.key(),
+        position_id,
+        amount,
+        duration_seconds,
+        xnos,
+        multiplier_bps,
+        tier,
+        unlock_at: stake_account.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(grant_id: u64, amount: u64, duration_seconds: i64, position_id: u64)]
+pub struct StakeGrant<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions (created on first stake)
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        space = Staker::SPACE,
+        seeds = [b"staker", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Vesting grant funding the position
+    #[account(
+        mut,
+        seeds = [b"grant", beneficiary.key().as_ref(), &grant_id.to_le_bytes()],
+        bump = grant.bump,
+        has_one = beneficiary @ StakingError::Unauthorized
+    )]
+    pub grant: Account<'info, VestingGrant>,
+
+    /// New stake position (PDA)
+    #[account(
+        init,
+        payer = beneficiary,
+        space = StakeAccount::SPACE,
+        seeds = [b"stake", beneficiary.key().as_ref(), &position_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Grant beneficiary (position owner)
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
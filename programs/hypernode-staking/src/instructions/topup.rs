@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Add HYPER to an existing stake
+///
+/// The added tokens are locked for the full stake duration, so:
+/// - unlock_at = max(unlock_at, now + stake_duration)
+/// - xNOS, multiplier and tier are recomputed on the new total
+pub fn topup(ctx: Context<Topup>, _position_id: u64, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: staking must be enabled
+    require!(config.staking_enabled, StakingError::StakingDisabled);
+
+    // Validation: stake still active
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+    require!(!stake_account.is_unbonding(), StakingError::AlreadyUnbonding);
+
+    // Validation: grant-funded positions hold only granted tokens
+    require!(stake_account.grant.is_none(), StakingError::GrantPosition);
+
+    // Validation: amount
+    require!(amount > 0, StakingError::InvalidAmount);
+    let new_amount = stake_account
+        .staked_amount
+        .checked_add(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+    require!(
+        new_amount <= config.max_stake_amount,
+        StakingError::AboveMaximum
+    );
+
+    // Recalculate xNOS on the new total
+    let (xnos, multiplier_bps) = StakeAccount::calculate_xnos(
+        new_amount,
+        stake_account.stake_duration,
+        &config.multiplier_curve,
+    )?;
+    let tier = StakeAccount::calculate_tier(xnos, &config.tier_thresholds);
+
+    // Transfer tokens to vault (escrow)
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Update global stats (xNOS by delta to keep reward shares consistent)
+    config.total_staked = config
+        .total_staked
+        .checked_add(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+    config.total_xnos = config
+        .total_xnos
+        .checked_sub(stake_account.xnos)
+        .and_then(|v| v.checked_add(xnos))
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update node delegation (delegated positions share node totals)
+    sync_delegation(
+        ctx.accounts.node_delegation.as_mut(),
+        NodeRewardsAccounts {
+            node_reward_pool: ctx.accounts.node_reward_pool.as_ref().map(|a| a.as_ref()),
+            delegator_rewards: ctx.accounts.delegator_rewards.as_ref().map(|a| a.as_ref()),
+            staking_authority: ctx.accounts.staking_authority.as_ref(),
+            rewards_program: ctx.accounts.rewards_program.as_ref(),
+            staking_authority_bump: ctx.bumps.staking_authority,
+        },
+        staker,
+        stake_account,
+        new_amount,
+        xnos,
+    )?;
+
+    // Update wallet aggregate
+    staker.debit(0, stake_account.xnos, &config.tier_thresholds)?;
+    staker.credit(amount, xnos, &config.tier_thresholds)?;
+
+    // Update stake account
+    let relock_at = clock
+        .unix_timestamp
+        .checked_add(stake_account.stake_duration)
+        .ok_or(StakingError::CalculationOverflow)?;
+    stake_account.staked_amount = new_amount;
+    stake_account.checkpoint_xnos(clock.unix_timestamp);
+    stake_account.unlock_at = stake_account.unlock_at.max(relock_at);
+    stake_account.xnos = xnos;
+    stake_account.multiplier_bps = multiplier_bps;
+    stake_account.tier = tier;
+
+    msg!(
+        "Topped up {} HYPER. Total staked: {}. xNOS: {}. Tier: {:?}",
+        amount,
+        new_amount,
+        xnos,
+        tier
+    );
+
+    emit!(TopupEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        amount,
+        staked_amount: new_amount,
+        xnos,
+        tier,
+        unlock_at: stake_account.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Topup<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being modified
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Node delegation (required if the position is delegated)
+    #[account(mut)]
+    pub node_delegation: Option<Account<'info, NodeDelegation>>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (required if the
+    /// position is delegated; checked against its node)
+    #[account(mut)]
+    pub node_reward_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Position's checkpoint PDA in that pool (required if delegated)
+    #[account(mut)]
+    pub delegator_rewards: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// User adding to stake
+    pub authority: Signer<'info>,
+
+    /// User's token account (HYPER)
+    #[account(
+        mut,
+        constraint = user_token_account.owner == authority.key(),
+        constraint = user_token_account.mint == config.mint @ StakingError::InvalidMint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Vault where staked tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Event emitted when a stake is topped up
+#[event]
+pub struct TopupEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub xnos: u64,
+    pub tier: StakeTier,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
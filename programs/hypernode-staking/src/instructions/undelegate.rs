@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Remove a stake position's delegation from its node
+///
+/// Required before `request_unstake`. Slashes already applied while
+/// delegated are not reversed. Node rewards earned so far are settled and
+/// stay claimable in hypernode-rewards.
+pub fn undelegate(ctx: Context<Undelegate>, _position_id: u64) -> Result<()> {
+    let delegation = &mut ctx.accounts.node_delegation;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: delegated to this node
+    require!(
+        stake_account.delegated_to.is_some(),
+        StakingError::NotDelegated
+    );
+    require!(
+        stake_account.delegated_to == Some(delegation.node),
+        StakingError::DelegationMismatch
+    );
+
+    // Settle node rewards before the delegated xNOS changes
+    settle_node_rewards(
+        &ctx.accounts.node_reward_pool.to_account_info(),
+        &ctx.accounts.delegator_rewards.to_account_info(),
+        &stake_account.to_account_info(),
+        &ctx.accounts.staking_authority.to_account_info(),
+        &ctx.accounts.rewards_program.to_account_info(),
+        ctx.bumps.staking_authority,
+        0,
+    )?;
+
+    ctx.accounts.staker.rebalance_delegated(stake_account.xnos, 0)?;
+    delegation.rebalance(stake_account.staked_amount, stake_account.xnos, 0, 0)?;
+    delegation.delegators = delegation.delegators.saturating_sub(1);
+
+    stake_account.delegated_to = None;
+
+    msg!(
+        "Undelegated position {} from node {}",
+        stake_account.position_id,
+        delegation.node
+    );
+
+    emit!(UndelegateEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        node: delegation.node,
+        amount: stake_account.staked_amount,
+        xnos: stake_account.xnos,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Undelegate<'info> {
+    /// Delegation account of the current node
+    #[account(
+        mut,
+        seeds = [b"delegation", node_delegation.node.as_ref()],
+        bump = node_delegation.bump
+    )]
+    pub node_delegation: Account<'info, NodeDelegation>,
+
+    /// Owner's wallet aggregate (xNOS returns to its own stake)
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being undelegated
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// CHECK: Node's reward pool PDA in hypernode-rewards (settled via CPI)
+    #[account(
+        mut,
+        seeds = [b"node_rewards", node_delegation.node.as_ref()],
+        bump,
+        seeds::program = rewards_program::ID
+    )]
+    pub node_reward_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Position's checkpoint PDA in that pool; settled if it exists
+    #[account(
+        mut,
+        seeds = [
+            b"delegator",
+            stake_account.key().as_ref(),
+            node_delegation.node.as_ref()
+        ],
+        bump,
+        seeds::program = rewards_program::ID
+    )]
+    pub delegator_rewards: UncheckedAccount<'info>,
+
+    /// CHECK: Staking authority PDA (signs CPIs into hypernode-rewards)
+    #[account(seeds = [b"staking_authority"], bump)]
+    pub staking_authority: UncheckedAccount<'info>,
+
+    /// CHECK: hypernode-rewards program
+    #[account(address = rewards_program::ID)]
+    pub rewards_program: UncheckedAccount<'info>,
+
+    /// Position owner
+    pub authority: Signer<'info>,
+}
+
+/// Event emitted when a position is undelegated
+#[event]
+pub struct UndelegateEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub node: Pubkey,
+    pub amount: u64,
+    pub xnos: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Update staking configuration (authority only)
+///
+/// Every field is optional; only provided values are changed.
+/// Existing positions keep their xNOS until `recalculate` is called.
+pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(authority) = params.authority {
+        config.authority = authority;
+    }
+    if let Some(min_stake_amount) = params.min_stake_amount {
+        config.min_stake_amount = min_stake_amount;
+    }
+    if let Some(max_stake_amount) = params.max_stake_amount {
+        config.max_stake_amount = max_stake_amount;
+    }
+    if let Some(min_duration) = params.min_duration {
+        config.min_duration = min_duration;
+    }
+    if let Some(max_duration) = params.max_duration {
+        config.max_duration = max_duration;
+    }
+    if let Some(unbonding_period) = params.unbonding_period {
+        config.unbonding_period = unbonding_period;
+    }
+    if let Some(tier_thresholds) = params.tier_thresholds {
+        config.tier_thresholds = tier_thresholds;
+    }
+    if let Some(multiplier_curve) = params.multiplier_curve {
+        config.multiplier_curve = multiplier_curve;
+    }
+    if let Some(staking_enabled) = params.staking_enabled {
+        config.staking_enabled = staking_enabled;
+    }
+    if let Some(xnos_decay_enabled) = params.xnos_decay_enabled {
+        config.xnos_decay_enabled = xnos_decay_enabled;
+    }
+
+    // Validate resulting config as a whole
+    require!(
+        config.min_stake_amount > 0 && config.min_stake_amount <= config.max_stake_amount,
+        StakingError::InvalidConfig
+    );
+    require!(
+        config.min_duration > 0 && config.min_duration <= config.max_duration,
+        StakingError::InvalidConfig
+    );
+    require!(config.unbonding_period >= 0, StakingError::InvalidConfig);
+    require!(config.tier_thresholds.is_valid(), StakingError::InvalidConfig);
+    require!(config.multiplier_curve.is_valid(), StakingError::InvalidConfig);
+
+    msg!("Staking config updated");
+    msg!("Authority: {}", config.authority);
+    msg!("Min/max stake: {} / {}", config.min_stake_amount, config.max_stake_amount);
+    msg!("Min/max duration: {} / {} seconds", config.min_duration, config.max_duration);
+    msg!("Staking enabled: {}", config.staking_enabled);
+    msg!("xNOS decay enabled: {}", config.xnos_decay_enabled);
+
+    emit!(ConfigUpdatedEvent {
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// Staking configuration
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Config authority
+    pub authority: Signer<'info>,
+}
+
+/// Optional config changes (None = keep current value)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateConfigParams {
+    pub authority: Option<Pubkey>,
+    pub min_stake_amount: Option<u64>,
+    pub max_stake_amount: Option<u64>,
+    pub min_duration: Option<i64>,
+    pub max_duration: Option<i64>,
+    pub unbonding_period: Option<i64>,
+    pub tier_thresholds: Option<TierThresholds>,
+    pub multiplier_curve: Option<MultiplierCurve>,
+    pub staking_enabled: Option<bool>,
+    pub xnos_decay_enabled: Option<bool>,
+}
+
+/// Event emitted when staking config changes
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+
+/// Withdraw HYPER after the unbonding period ends (phase 2 of 2)
+///
+/// Returns the position's remaining (possibly slashed) tokens to the owner.
+/// Grant-funded positions return them to the vesting grant instead, so
+/// unvested tokens never leave the vault. The position is marked withdrawn
+/// and kept for history.
+pub fn withdraw(ctx: Context<Withdraw>, _position_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let staker = &mut ctx.accounts.staker;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let clock = Clock::get()?;
+
+    // Validation: not already withdrawn
+    require!(!stake_account.withdrawn, StakingError::AlreadyWithdrawn);
+
+    // Validation: unbonding must have been requested and completed
+    let withdrawable_at = stake_account
+        .withdrawable_at
+        .ok_or(StakingError::NotUnbonding)?;
+    require!(
+        clock.unix_timestamp >= withdrawable_at,
+        StakingError::UnbondingNotComplete
+    );
+
+    let amount = stake_account.staked_amount;
+
+    match stake_account.grant {
+        // Grant-funded: tokens stay in the vault, back to the grant
+        Some(grant_key) => {
+            let grant = ctx
+                .accounts
+                .grant
+                .as_mut()
+                .ok_or(StakingError::GrantMismatch)?;
+            require!(grant.key() == grant_key, StakingError::GrantMismatch);
+
+            grant.staked = grant
+                .staked
+                .checked_sub(amount)
+                .ok_or(StakingError::CalculationOverflow)?;
+        }
+        // Transfer tokens from vault back to user
+        None => {
+            let config_seeds = &[b"config".as_ref(), &[config.bump]];
+            let signer = &[&config_seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: config.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, amount)?;
+        }
+    }
+
+    // Update global stats (xNOS was already burned at request time)
+    config.total_staked = config
+        .total_staked
+        .checked_sub(amount)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    // Update wallet aggregate
+    staker.debit(amount, 0, &config.tier_thresholds)?;
+    staker.active_positions = staker.active_positions.saturating_sub(1);
+    if staker.active_positions == 0 {
+        config.total_stakers = config.total_stakers.saturating_sub(1);
+    }
+
+    // Mark as withdrawn (keep account for history)
+    stake_account.withdrawn = true;
+
+    msg!("Withdrew {} HYPER.", amount);
+
+    // Emit event
+    emit!(WithdrawEvent {
+        user: ctx.accounts.authority.key(),
+        position_id: stake_account.position_id,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Withdraw<'info> {
+    /// Staking configuration (needed for PDA signing)
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakingConfig>,
+
+    /// Wallet aggregate across positions
+    #[account(
+        mut,
+        seeds = [b"staker", authority.key().as_ref()],
+        bump = staker.bump
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// Stake position being withdrawn
+    #[account(
+        mut,
+        seeds = [b"stake", authority.key().as_ref(), &position_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = authority @ StakingError::Unauthorized
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Vesting grant (required if the position is grant-funded)
+    #[account(mut)]
+    pub grant: Option<Account<'info, VestingGrant>>,
+
+    /// User withdrawing stake
+    pub authority: Signer<'info>,
+
+    /// User's token account (HYPER destination)
+    #[account(
+        mut,
+        constraint = user_token_account.owner == authority.key(),
+        constraint = user_token_account.mint == config.mint @ StakingError::InvalidMint
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Vault where staked tokens are held (config PDA-owned)
+    #[account(
+        mut,
+        address = config.vault @ StakingError::InvalidVault
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// SPL Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Event emitted when unbonded tokens are withdrawn
+#[event]
+pub struct WithdrawEvent {
+    pub user: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -14,8 +14,13 @@ declare_id!("HYPRstake1111111111111111111111111111111111");
 /// Core Features:
 /// - Time-based multipliers (1x to 4x based on lock duration)
 /// - Tier system (Starter → Diamond based on xNOS)
-/// - Trustless escrow with time-locks
+/// - Multiple independent positions per wallet, aggregated per Staker
+/// - Trustless escrow with time-locks and unbonding cooldown
 /// - Global stats tracking
+/// - Slashing by a configured slasher (treasury or burn)
+/// - Optional vote-escrow mode (xNOS decays with remaining lock)
+/// - Delegation of stake to node operators (with commission)
+/// - Vesting grants that can be staked while locked
 ///
 /// Architecture Principles:
 /// - Trustless: Time-locks enforced on-chain
@@ -33,6 +38,7 @@ pub mod hypernode_staking {
     /// - Min/max durations
     /// - Tier thresholds
     /// - Authority
+    /// - Stake vault (PDA token account) and pinned HYPER mint
     pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
         instructions::initialize_config(ctx)
     }
@@ -42,28 +48,192 @@ pub mod hypernode_staking {
     /// Users lock HYPER for specified duration:
     /// - amount: HYPER tokens to stake
     /// - duration_seconds: Lock period (longer = higher multiplier)
+    /// - position_id: Index of the new position (Staker.next_position_id)
     ///
     /// Returns xNOS based on formula:
     /// xNOS = amount * multiplier
     ///
-    /// Multipliers:
-    /// - 1 month: 1x
-    /// - 3 months: 1.5x
-    /// - 6 months: 2x
+    /// Multipliers come from config.multiplier_curve (default):
+    /// - < 1 month: 1x
+    /// - 1 month: 1.5x
+    /// - 3 months: 2x
+    /// - 6 months: 3x
     /// - 1 year: 4x
     pub fn stake(
         ctx: Context<Stake>,
         amount: u64,
         duration_seconds: i64,
+        position_id: u64,
     ) -> Result<()> {
-        instructions::stake(ctx, amount, duration_seconds)
+        instructions::stake(ctx, amount, duration_seconds, position_id)
     }
 
-    /// Unstake HYPER tokens after lock period
+    /// Request unstake of a position after its lock period
     ///
-    /// Withdraws staked HYPER once unlock_at is reached.
-    /// Burns xNOS (voting power is lost).
-    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
-        instructions::unstake(ctx)
+    /// Burns the position's xNOS (voting power is lost) and starts
+    /// the unbonding cooldown. Tokens remain slashable until withdrawn.
+    pub fn request_unstake(ctx: Context<RequestUnstake>, position_id: u64) -> Result<()> {
+        instructions::request_unstake(ctx, position_id)
+    }
+
+    /// Withdraw HYPER once the unbonding period has ended
+    pub fn withdraw(ctx: Context<Withdraw>, position_id: u64) -> Result<()> {
+        instructions::withdraw(ctx, position_id)
+    }
+
+    /// Cancel a pending unstake and relock the position
+    ///
+    /// Restores xNOS; the position is locked again for its full duration.
+    pub fn restake(ctx: Context<Restake>, position_id: u64) -> Result<()> {
+        instructions::restake(ctx, position_id)
+    }
+
+    /// Add HYPER to an existing stake
+    ///
+    /// - amount: Additional HYPER tokens to lock
+    ///
+    /// Recomputes xNOS and tier on the new total.
+    /// New tokens are locked for the full stake duration.
+    pub fn topup(ctx: Context<Topup>, position_id: u64, amount: u64) -> Result<()> {
+        instructions::topup(ctx, position_id, amount)
+    }
+
+    /// Extend the lock period of an existing stake
+    ///
+    /// - additional_seconds: Added to stake duration and unlock_at
+    ///
+    /// Longer locks move the stake into a higher multiplier bracket.
+    pub fn extend(
+        ctx: Context<Extend>,
+        position_id: u64,
+        additional_seconds: i64,
+    ) -> Result<()> {
+        instructions::extend(ctx, position_id, additional_seconds)
+    }
+
+    /// Configure the slasher and slash destination (authority only)
+    ///
+    /// - slasher: Key or program PDA allowed to slash
+    /// - treasury: Token account for slashed HYPER (None = burn)
+    pub fn set_slasher(
+        ctx: Context<SetSlasher>,
+        slasher: Pubkey,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_slasher(ctx, slasher, treasury)
+    }
+
+    /// Slash a stake position for misbehaviour (slasher only)
+    ///
+    /// - amount_bps: Share of the position to slash (e.g., 5000 = 50%)
+    /// - reason: Short description recorded in SlashEvent
+    ///
+    /// Works during the lock; reduces staked amount, xNOS and tier.
+    pub fn slash(
+        ctx: Context<Slash>,
+        position_id: u64,
+        amount_bps: u16,
+        reason: String,
+    ) -> Result<()> {
+        instructions::slash(ctx, position_id, amount_bps, reason)
+    }
+
+    /// Update staking parameters (authority only)
+    ///
+    /// Optional changes to:
+    /// - Authority (e.g. hand over to the governance PDA)
+    /// - Min/max stake amounts and durations
+    /// - Unbonding period
+    /// - Tier thresholds and multiplier curve
+    /// - staking_enabled
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config(ctx, params)
+    }
+
+    /// Refresh a position's xNOS and tier from current config (permissionless)
+    pub fn recalculate(ctx: Context<Recalculate>, position_id: u64) -> Result<()> {
+        instructions::recalculate(ctx, position_id)
+    }
+
+    /// Get a wallet's effective xNOS at a timestamp (view)
+    ///
+    /// - at_timestamp: Point in time to evaluate (e.g., now or a snapshot)
+    ///
+    /// With vote-escrow mode enabled, each position decays linearly:
+    /// xNOS * min(unlock_at - t, duration) / duration
+    ///
+    /// All active positions are passed as remaining accounts.
+    pub fn get_voting_power<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetVotingPower<'info>>,
+        at_timestamp: i64,
+    ) -> Result<u64> {
+        instructions::get_voting_power(ctx, at_timestamp)
+    }
+
+    /// Open a node for delegation (node operator only)
+    ///
+    /// - commission_bps: Operator share of node earnings (max 10000)
+    pub fn init_delegation(ctx: Context<InitDelegation>, commission_bps: u16) -> Result<()> {
+        instructions::init_delegation(ctx, commission_bps)
+    }
+
+    /// Schedule an operator commission change (node operator only)
+    ///
+    /// Takes effect one unbonding period after being set.
+    pub fn set_commission(ctx: Context<SetCommission>, commission_bps: u16) -> Result<()> {
+        instructions::set_commission(ctx, commission_bps)
+    }
+
+    /// Delegate a stake position to a node
+    ///
+    /// Delegated xNOS counts toward the node's market eligibility
+    /// and shares its slashing risk. Settles node rewards via CPI.
+    pub fn delegate(ctx: Context<Delegate>, position_id: u64) -> Result<()> {
+        instructions::delegate(ctx, position_id)
+    }
+
+    /// Remove a position's delegation (required before unstaking)
+    ///
+    /// Settles node rewards earned so far via CPI.
+    pub fn undelegate(ctx: Context<Undelegate>, position_id: u64) -> Result<()> {
+        instructions::undelegate(ctx, position_id)
+    }
+
+    /// Close a node's delegation once all delegators have left
+    pub fn close_delegation(ctx: Context<CloseDelegation>) -> Result<()> {
+        instructions::close_delegation(ctx)
+    }
+
+    /// Create a vesting grant (cliff + linear) for a beneficiary
+    ///
+    /// Tokens are deposited into the stake vault and stay locked until vested.
+    pub fn create_grant(
+        ctx: Context<CreateGrant>,
+        grant_id: u64,
+        beneficiary: Pubkey,
+        amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_grant(ctx, grant_id, beneficiary, amount, start_ts, cliff_ts, end_ts)
+    }
+
+    /// Stake locked grant tokens (beneficiary only)
+    ///
+    /// Withdrawn positions return tokens to the grant, not the wallet.
+    pub fn stake_grant(
+        ctx: Context<StakeGrant>,
+        grant_id: u64,
+        amount: u64,
+        duration_seconds: i64,
+        position_id: u64,
+    ) -> Result<()> {
+        instructions::stake_grant(ctx, grant_id, amount, duration_seconds, position_id)
+    }
+
+    /// Claim vested, unstaked grant tokens
+    pub fn claim_vested(ctx: Context<ClaimVested>, grant_id: u64) -> Result<()> {
+        instructions::claim_vested(ctx, grant_id)
     }
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{StakeAccount, StakeTier};
 
 /// Global staking configuration
 ///
@@ -8,6 +9,18 @@ pub struct StakingConfig {
     /// Authority that can update config (multisig/governance)
     pub authority: Pubkey,
 
+    /// Key allowed to slash stakes (authority, or a program PDA signing via CPI)
+    pub slasher: Pubkey,
+
+    /// Token account receiving slashed HYPER (None = burn)
+    pub slash_treasury: Option<Pubkey>,
+
+    /// HYPER mint accepted for staking
+    pub mint: Pubkey,
+
+    /// Stake vault (PDA token account owned by this config)
+    pub vault: Pubkey,
+
     /// Minimum stake amount (in lamports)
     pub min_stake_amount: u64,
 
@@ -20,12 +33,21 @@ pub struct StakingConfig {
     /// Maximum stake duration (in seconds, default 2 years)
     pub max_duration: i64,
 
+    /// Cooldown between unstake request and withdraw (in seconds)
+    pub unbonding_period: i64,
+
     /// Tier thresholds (xNOS amounts)
     pub tier_thresholds: TierThresholds,
 
+    /// Duration → multiplier curve used for xNOS
+    pub multiplier_curve: MultiplierCurve,
+
     /// Whether staking is currently enabled
     pub staking_enabled: bool,
 
+    /// Opt-in vote-escrow mode: effective xNOS decays linearly to zero at unlock
+    pub xnos_decay_enabled: bool,
+
     /// Total HYPER staked across all accounts
     pub total_staked: u64,
 
@@ -42,32 +64,65 @@ pub struct StakingConfig {
 impl StakingConfig {
     pub const SPACE: usize = 8 + // discriminator
         32 + // authority
+        32 + // slasher
+        1 + 32 + // slash_treasury (Option<Pubkey>)
+        32 + // mint
+        32 + // vault
         8 + // min_stake_amount
         8 + // max_stake_amount
         8 + // min_duration
         8 + // max_duration
+        8 + // unbonding_period
         40 + // tier_thresholds (5 * 8)
+        MultiplierCurve::SPACE + // multiplier_curve
         1 + // staking_enabled
+        1 + // xnos_decay_enabled
         8 + // total_staked
         8 + // total_xnos
         8 + // total_stakers
         1; // bump
 
+    /// Effective xNOS of a position at a timestamp
+    ///
+    /// Static stored xNOS, or the linearly decaying value when
+    /// vote-escrow mode is enabled.
+    pub fn effective_xnos(&self, stake_account: &StakeAccount, at_timestamp: i64) -> u64 {
+        if self.xnos_decay_enabled {
+            stake_account.decayed_xnos(at_timestamp)
+        } else {
+            stake_account.xnos
+        }
+    }
+
+    /// Default unbonding period: 7 days
+    pub const DEFAULT_UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60;
+
     /// Default configuration (used during initialization)
-    pub fn default_config() -> (u64, u64, i64, i64, TierThresholds) {
+    pub fn default_config() -> (u64, u64, i64, i64, TierThresholds, MultiplierCurve) {
         const DAY: i64 = 24 * 60 * 60;
+        const MONTH: i64 = 30 * DAY;
         const YEAR: i64 = 365 * DAY;
+        const HYPER: u64 = 1_000_000_000; // 9 decimals
 
         (
-            1_000_000_000, // min_stake_amount: 1 HYPER (9 decimals)
-            1_000_000_000_000_000, // max_stake_amount: 1M HYPER
+            HYPER, // min_stake_amount: 1 HYPER
+            1_000_000 * HYPER, // max_stake_amount: 1M HYPER
             DAY, // min_duration: 1 day
             2 * YEAR, // max_duration: 2 years
             TierThresholds {
-                bronze: 1_000,
-                silver: 10_000,
-                gold: 50_000,
-                diamond: 100_000,
+                bronze: 1_000 * HYPER,
+                silver: 10_000 * HYPER,
+                gold: 50_000 * HYPER,
+                diamond: 100_000 * HYPER,
+            },
+            MultiplierCurve {
+                steps: [
+                    MultiplierStep { min_duration: 0, multiplier_bps: 100 }, // 1x
+                    MultiplierStep { min_duration: MONTH, multiplier_bps: 150 }, // 1.5x
+                    MultiplierStep { min_duration: 3 * MONTH, multiplier_bps: 200 }, // 2x
+                    MultiplierStep { min_duration: 6 * MONTH, multiplier_bps: 300 }, // 3x
+                    MultiplierStep { min_duration: YEAR, multiplier_bps: 400 }, // 4x
+                ],
             },
         )
     }
@@ -90,3 +145,70 @@ pub struct TierThresholds {
     /// Diamond tier threshold
     pub diamond: u64,
 }
+
+impl TierThresholds {
+    /// Determine tier for an xNOS amount
+    pub fn tier_for(&self, xnos: u64) -> StakeTier {
+        if xnos >= self.diamond {
+            StakeTier::Diamond
+        } else if xnos >= self.gold {
+            StakeTier::Gold
+        } else if xnos >= self.silver {
+            StakeTier::Silver
+        } else if xnos >= self.bronze {
+            StakeTier::Bronze
+        } else {
+            StakeTier::Starter
+        }
+    }
+
+    /// Thresholds must be strictly increasing
+    pub fn is_valid(&self) -> bool {
+        self.bronze < self.silver && self.silver < self.gold && self.gold < self.diamond
+    }
+}
+
+/// Duration multiplier curve
+///
+/// A stake earns the multiplier of the highest step whose
+/// min_duration it reaches. The first step must start at 0.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MultiplierCurve {
+    pub steps: [MultiplierStep; MultiplierCurve::STEPS],
+}
+
+/// One step of the multiplier curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MultiplierStep {
+    /// Minimum lock duration for this step (in seconds)
+    pub min_duration: i64,
+
+    /// Multiplier in basis points (100 = 1x, 400 = 4x)
+    pub multiplier_bps: u16,
+}
+
+impl MultiplierCurve {
+    pub const STEPS: usize = 5;
+    pub const SPACE: usize = Self::STEPS * (8 + 2);
+
+    /// Multiplier (bps) for a lock duration
+    pub fn multiplier_for(&self, duration_seconds: i64) -> u16 {
+        self.steps
+            .iter()
+            .rev()
+            .find(|step| duration_seconds >= step.min_duration)
+            .map(|step| step.multiplier_bps)
+            .unwrap_or(self.steps[0].multiplier_bps)
+    }
+
+    /// Steps must start at 0, have increasing durations
+    /// and non-decreasing, non-zero multipliers
+    pub fn is_valid(&self) -> bool {
+        self.steps[0].min_duration == 0
+            && self.steps[0].multiplier_bps > 0
+            && self.steps.windows(2).all(|w| {
+                w[0].min_duration < w[1].min_duration
+                    && w[0].multiplier_bps <= w[1].multiplier_bps
+            })
+    }
+}
@@ -1,5 +1,11 @@
 pub mod stake_account;
+pub mod staker;
+pub mod node_delegation;
+pub mod vesting_grant;
 pub mod config;
 
 pub use stake_account::*;
+pub use staker::*;
+pub use node_delegation::*;
+pub use vesting_grant::*;
 pub use config::*;
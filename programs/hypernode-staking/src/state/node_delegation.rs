@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::{StakeAccount, Staker, StakingError};
+
+/// hypernode-rewards program ID (node reward pools)
+///
+/// Hardcoded because hypernode-rewards depends on this crate.
+pub mod rewards_program {
+    anchor_lang::declare_id!("HYPRreward111111111111111111111111111111111");
+}
+
+/// Delegated stake backing a hypernode-nodes `Node`
+///
+/// Token holders who don't run hardware delegate stake positions to a node.
+/// Delegated xNOS counts toward the node's market eligibility and shares its
+/// slashing risk. The operator takes `commission_bps` of node earnings
+/// distributed through the rewards program; the rest goes to delegators.
+/// Commission changes take effect one unbonding period after being set, so
+/// delegators can leave before a raise applies.
+#[account]
+pub struct NodeDelegation {
+    /// Node PDA (hypernode-nodes) receiving delegations
+    pub node: Pubkey,
+
+    /// Node operator (Node.authority)
+    pub operator: Pubkey,
+
+    /// Operator commission on node earnings (basis points)
+    pub commission_bps: u16,
+
+    /// Scheduled commission change (basis points)
+    pub pending_commission_bps: Option<u16>,
+
+    /// When the scheduled commission takes effect
+    pub commission_effective_at: i64,
+
+    /// Total HYPER delegated to this node
+    pub delegated_amount: u64,
+
+    /// Total xNOS delegated to this node
+    pub delegated_xnos: u64,
+
+    /// Number of positions currently delegated
+    pub delegators: u32,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl NodeDelegation {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // node
+        32 + // operator
+        2 + // commission_bps
+        (1 + 2) + // pending_commission_bps
+        8 + // commission_effective_at
+        8 + // delegated_amount
+        8 + // delegated_xnos
+        4 + // delegators
+        1; // bump
+
+    /// Commission in effect at a timestamp
+    pub fn commission_at(&self, now: i64) -> u16 {
+        match self.pending_commission_bps {
+            Some(pending) if now >= self.commission_effective_at => pending,
+            _ => self.commission_bps,
+        }
+    }
+
+    /// Fold a scheduled commission that has taken effect into commission_bps
+    pub fn apply_pending_commission(&mut self, now: i64) {
+        if self.pending_commission_bps.is_some() && now >= self.commission_effective_at {
+            self.commission_bps = self.commission_at(now);
+            self.pending_commission_bps = None;
+        }
+    }
+
+    /// Replace a delegated position's old amount/xNOS with new values
+    pub fn rebalance(
+        &mut self,
+        old_amount: u64,
+        old_xnos: u64,
+        new_amount: u64,
+        new_xnos: u64,
+    ) -> Result<()> {
+        self.delegated_amount = self
+            .delegated_amount
+            .checked_sub(old_amount)
+            .and_then(|v| v.checked_add(new_amount))
+            .ok_or(StakingError::CalculationOverflow)?;
+        self.delegated_xnos = self
+            .delegated_xnos
+            .checked_sub(old_xnos)
+            .and_then(|v| v.checked_add(new_xnos))
+            .ok_or(StakingError::CalculationOverflow)?;
+        Ok(())
+    }
+}
+
+/// A delegated position's accounts in its node's reward pool
+///
+/// Passed to `sync_delegation`; the pool accounts are only required (and
+/// checked against the position's node) for delegated positions.
+pub struct NodeRewardsAccounts<'a, 'info> {
+    pub node_reward_pool: Option<&'a AccountInfo<'info>>,
+    pub delegator_rewards: Option<&'a AccountInfo<'info>>,
+    pub staking_authority: &'a AccountInfo<'info>,
+    pub rewards_program: &'a AccountInfo<'info>,
+    pub staking_authority_bump: u8,
+}
+
+/// Keep a position's node delegation in sync after its amount or xNOS changes
+///
+/// No-op for undelegated positions. Delegated positions must pass the
+/// `NodeDelegation` of the node they are delegated to and their accounts in
+/// that node's reward pool: rewards are settled at the old xNOS first, so
+/// the pool's entered xNOS follows the position. The owner's
+/// `Staker.delegated_xnos` follows the same change.
+pub fn sync_delegation<'info>(
+    delegation: Option<&mut Account<'info, NodeDelegation>>,
+    rewards: NodeRewardsAccounts<'_, 'info>,
+    staker: &mut Staker,
+    stake_account: &Account<'info, StakeAccount>,
+    new_amount: u64,
+    new_xnos: u64,
+) -> Result<()> {
+    let Some(node) = stake_account.delegated_to else {
+        return Ok(());
+    };
+
+    let delegation = delegation.ok_or(StakingError::DelegationMismatch)?;
+    require!(delegation.node == node, StakingError::DelegationMismatch);
+
+    // Settle node rewards before the delegated xNOS changes
+    let (pool_key, _) = Pubkey::find_program_address(
+        &[b"node_rewards", node.as_ref()],
+        &rewards_program::ID,
+    );
+    let (delegator_key, _) = Pubkey::find_program_address(
+        &[b"delegator", stake_account.key().as_ref(), node.as_ref()],
+        &rewards_program::ID,
+    );
+    let (Some(node_reward_pool), Some(delegator_rewards)) =
+        (rewards.node_reward_pool, rewards.delegator_rewards)
+    else {
+        return err!(StakingError::NodeRewardsMismatch);
+    };
+    require!(
+        node_reward_pool.key() == pool_key && delegator_rewards.key() == delegator_key,
+        StakingError::NodeRewardsMismatch
+    );
+    settle_node_rewards(
+        node_reward_pool,
+        delegator_rewards,
+        &stake_account.to_account_info(),
+        rewards.staking_authority,
+        rewards.rewards_program,
+        rewards.staking_authority_bump,
+        new_xnos,
+    )?;
+
+    staker.rebalance_delegated(stake_account.xnos, new_xnos)?;
+    delegation.rebalance(
+        stake_account.staked_amount,
+        stake_account.xnos,
+        new_amount,
+        new_xnos,
+    )
+}
+
+/// Settle a position's rewards in its node's reward pool (hypernode-rewards)
+///
+/// Called on delegate/undelegate and on any change to a delegated position,
+/// before the position changes, so rewards are checkpointed whenever the
+/// position's delegated xNOS does. No-op for positions that never entered
+/// the node's pool. `xnos` is the position's new weight in the pool: its
+/// new xNOS while delegated, 0 when undelegating.
+pub fn settle_node_rewards<'info>(
+    node_reward_pool: &AccountInfo<'info>,
+    delegator_rewards: &AccountInfo<'info>,
+    stake_account: &AccountInfo<'info>,
+    staking_authority: &AccountInfo<'info>,
+    rewards_program: &AccountInfo<'info>,
+    staking_authority_bump: u8,
+    xnos: u64,
+) -> Result<()> {
+    if delegator_rewards.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut data = hash(b"global:settle_delegation").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&xnos.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: rewards_program::ID,
+        accounts: vec![
+            AccountMeta::new(node_reward_pool.key(), false),
+            AccountMeta::new(delegator_rewards.key(), false),
+            AccountMeta::new_readonly(stake_account.key(), false),
+            AccountMeta::new_readonly(staking_authority.key(), true),
+        ],
+        data,
+    };
+    let account_infos = [
+        node_reward_pool.clone(),
+        delegator_rewards.clone(),
+        stake_account.clone(),
+        staking_authority.clone(),
+        rewards_program.clone(),
+    ];
+    let seeds = &[b"staking_authority".as_ref(), &[staking_authority_bump]];
+
+    invoke_signed(&instruction, &account_infos, &[&seeds[..]])?;
+
+    Ok(())
+}
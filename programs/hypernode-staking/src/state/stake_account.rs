@@ -1,17 +1,21 @@
 use anchor_lang::prelude::*;
+use crate::state::{MultiplierCurve, TierThresholds};
 
-/// User's staking account with xNOS calculation
+/// A single stake position with xNOS calculation
 ///
-/// Implements time-based multipliers for xNOS calculation:
-/// - 1 month = 1x multiplier
-/// - 3 months = 1.5x multiplier
-/// - 6 months = 2x multiplier
-/// - 1 year = 4x multiplier
+/// A wallet can open many positions (PDA: [b"stake", authority, position_id]);
+/// their totals are aggregated in the wallet's `Staker` account.
+///
+/// Implements time-based multipliers for xNOS calculation,
+/// using the multiplier curve in `StakingConfig`.
 #[account]
 pub struct StakeAccount {
     /// Owner of this stake account
     pub authority: Pubkey,
 
+    /// Position index within the owner's `Staker` (PDA seed)
+    pub position_id: u64,
+
     /// Amount of HYPER staked
     pub staked_amount: u64,
 
@@ -33,16 +37,37 @@ pub struct StakeAccount {
     /// Tier level (Starter, Bronze, Silver, Gold, Diamond)
     pub tier: StakeTier,
 
+    /// When unbonding completes (Some = unstake requested, xNOS burned)
+    pub withdrawable_at: Option<i64>,
+
+    /// Node (hypernode-nodes PDA) this position is delegated to, if any
+    pub delegated_to: Option<Pubkey>,
+
+    /// Vesting grant funding this position (tokens return to it on withdraw)
+    pub grant: Option<Pubkey>,
+
     /// Whether stake has been withdrawn
     pub withdrawn: bool,
 
+    /// When the current xNOS/lock values took effect
+    pub xnos_updated_at: i64,
+
+    /// Earlier xNOS/lock values, newest first (governance snapshots)
+    pub xnos_history: [XnosCheckpoint; StakeAccount::XNOS_HISTORY_LEN],
+
     /// PDA bump
     pub bump: u8,
 }
 
 impl StakeAccount {
+    pub const MAX_SLASH_REASON_LEN: usize = 128;
+
+    /// Number of earlier xNOS values kept for snapshots
+    pub const XNOS_HISTORY_LEN: usize = 4;
+
     pub const SPACE: usize = 8 + // discriminator
         32 + // authority
+        8 + // position_id
         8 + // staked_amount
         8 + // stake_duration
         8 + // staked_at
@@ -50,34 +75,70 @@ impl StakeAccount {
         8 + // xnos
         2 + // multiplier_bps
         1 + // tier
+        1 + 8 + // withdrawable_at (Option<i64>)
+        1 + 32 + // delegated_to (Option<Pubkey>)
+        1 + 32 + // grant (Option<Pubkey>)
         1 + // withdrawn
+        8 + // xnos_updated_at
+        XnosCheckpoint::SPACE * Self::XNOS_HISTORY_LEN + // xnos_history
         1; // bump
 
+    /// Record the current xNOS/lock values before they change
+    ///
+    /// Must be called by every instruction changing xnos, unlock_at or
+    /// stake_duration, so `at` can reconstruct the position at a snapshot.
+    pub fn checkpoint_xnos(&mut self, now: i64) {
+        if now <= self.xnos_updated_at {
+            return;
+        }
+
+        self.xnos_history.rotate_right(1);
+        self.xnos_history[0] = XnosCheckpoint {
+            from: self.xnos_updated_at,
+            xnos: self.xnos,
+            unlock_at: self.unlock_at,
+            stake_duration: self.stake_duration,
+        };
+        self.xnos_updated_at = now;
+    }
+
+    /// The position's xNOS/lock values as of a past timestamp
+    ///
+    /// Topups, extends, restakes and slashes after the timestamp are
+    /// ignored. A position changed more than XNOS_HISTORY_LEN times since
+    /// then can't be reconstructed and counts as zero xNOS.
+    pub fn at(&self, at_timestamp: i64) -> StakeAccount {
+        if at_timestamp >= self.xnos_updated_at {
+            return self.clone();
+        }
+
+        let checkpoint = self
+            .xnos_history
+            .iter()
+            .find(|checkpoint| checkpoint.from <= at_timestamp)
+            .copied()
+            .unwrap_or_default();
+
+        StakeAccount {
+            xnos: checkpoint.xnos,
+            unlock_at: checkpoint.unlock_at,
+            stake_duration: checkpoint.stake_duration,
+            ..self.clone()
+        }
+    }
+
     /// Calculate xNOS based on staked amount and duration
     ///
     /// Formula: xNOS = staked_amount * multiplier
     ///
-    /// Duration multipliers (Nosana-inspired):
-    /// - < 1 month: 1x (100 bps)
-    /// - 1-3 months: 1.5x (150 bps)
-    /// - 3-6 months: 2x (200 bps)
-    /// - 6-12 months: 3x (300 bps)
-    /// - >= 1 year: 4x (400 bps)
-    pub fn calculate_xnos(amount: u64, duration_seconds: i64) -> Result<(u64, u16)> {
-        const MONTH: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
-        const YEAR: i64 = 365 * 24 * 60 * 60; // 365 days in seconds
-
-        let multiplier_bps = if duration_seconds >= YEAR {
-            400 // 4x
-        } else if duration_seconds >= 6 * MONTH {
-            300 // 3x
-        } else if duration_seconds >= 3 * MONTH {
-            200 // 2x
-        } else if duration_seconds >= MONTH {
-            150 // 1.5x
-        } else {
-            100 // 1x
-        };
+    /// The multiplier comes from the config's duration curve
+    /// (default: 1x, 1.5x at 1 month, 2x at 3, 3x at 6, 4x at 1 year).
+    pub fn calculate_xnos(
+        amount: u64,
+        duration_seconds: i64,
+        curve: &MultiplierCurve,
+    ) -> Result<(u64, u16)> {
+        let multiplier_bps = curve.multiplier_for(duration_seconds);
 
         // Calculate xNOS: amount * multiplier / 100
         let xnos = (amount as u128)
@@ -89,27 +150,67 @@ impl StakeAccount {
         Ok((xnos, multiplier_bps))
     }
 
-    /// Determine tier based on xNOS amount
+    /// xNOS decayed linearly with remaining lock time (vote-escrow style)
     ///
-    /// Tiers (adjustable in config):
-    /// - Starter: 0-999 xNOS
-    /// - Bronze: 1,000-9,999 xNOS
-    /// - Silver: 10,000-49,999 xNOS
-    /// - Gold: 50,000-99,999 xNOS
-    /// - Diamond: 100,000+ xNOS
-    pub fn calculate_tier(xnos: u64) -> StakeTier {
-        if xnos >= 100_000 {
-            StakeTier::Diamond
-        } else if xnos >= 50_000 {
-            StakeTier::Gold
-        } else if xnos >= 10_000 {
-            StakeTier::Silver
-        } else if xnos >= 1_000 {
-            StakeTier::Bronze
-        } else {
-            StakeTier::Starter
+    /// Formula: xNOS * min(unlock_at - t, stake_duration) / stake_duration
+    ///
+    /// Full value while at least a full duration remains, zero at unlock.
+    /// Unbonding and withdrawn positions already carry zero xNOS.
+    pub fn decayed_xnos(&self, at_timestamp: i64) -> u64 {
+        if self.stake_duration <= 0 {
+            return 0;
         }
+
+        let remaining = self
+            .unlock_at
+            .saturating_sub(at_timestamp)
+            .clamp(0, self.stake_duration);
+
+        // remaining <= stake_duration, so the result fits in u64
+        ((self.xnos as u128) * (remaining as u128) / (self.stake_duration as u128)) as u64
     }
+
+    /// Whether an unstake has been requested (position is unbonding)
+    ///
+    /// Unbonding positions carry zero xNOS but remain slashable.
+    pub fn is_unbonding(&self) -> bool {
+        self.withdrawable_at.is_some()
+    }
+
+    /// Determine tier based on xNOS amount
+    ///
+    /// Thresholds come from config (default, in HYPER-denominated xNOS):
+    /// - Starter: below 1,000
+    /// - Bronze: 1,000+
+    /// - Silver: 10,000+
+    /// - Gold: 50,000+
+    /// - Diamond: 100,000+
+    pub fn calculate_tier(xnos: u64, thresholds: &TierThresholds) -> StakeTier {
+        thresholds.tier_for(xnos)
+    }
+}
+
+/// A position's xNOS/lock values from a timestamp until the next change
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct XnosCheckpoint {
+    /// When these values took effect
+    pub from: i64,
+
+    /// xNOS from then on
+    pub xnos: u64,
+
+    /// Unlock timestamp from then on
+    pub unlock_at: i64,
+
+    /// Lock duration from then on
+    pub stake_duration: i64,
+}
+
+impl XnosCheckpoint {
+    pub const SPACE: usize = 8 + // from
+        8 + // xnos
+        8 + // unlock_at
+        8; // stake_duration
 }
 
 /// Staking tiers based on xNOS amount
@@ -119,13 +220,13 @@ impl StakeAccount {
 /// - Reduced fees
 /// - Access to premium markets
 /// - Governance voting power
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum StakeTier {
-    Starter,  // 0-999 xNOS
-    Bronze,   // 1,000-9,999 xNOS
-    Silver,   // 10,000-49,999 xNOS
-    Gold,     // 50,000-99,999 xNOS
-    Diamond,  // 100,000+ xNOS
+    Starter,  // below bronze threshold
+    Bronze,   // >= tier_thresholds.bronze
+    Silver,   // >= tier_thresholds.silver
+    Gold,     // >= tier_thresholds.gold
+    Diamond,  // >= tier_thresholds.diamond
 }
 
 #[error_code]
@@ -147,4 +248,94 @@ pub enum StakingError {
 
     #[msg("Amount must be greater than zero")]
     InvalidAmount,
+
+    #[msg("Amount is below minimum stake")]
+    BelowMinimum,
+
+    #[msg("Amount exceeds maximum stake per account")]
+    AboveMaximum,
+
+    #[msg("Staking is currently disabled")]
+    StakingDisabled,
+
+    #[msg("Only stake owner can perform this action")]
+    Unauthorized,
+
+    #[msg("Position id must equal the staker's next position id")]
+    InvalidPositionId,
+
+    #[msg("Only the configured slasher can slash stakes")]
+    UnauthorizedSlasher,
+
+    #[msg("Slash amount must be between 1 and 10000 bps")]
+    InvalidSlashBps,
+
+    #[msg("Slash reason exceeds maximum length")]
+    SlashReasonTooLong,
+
+    #[msg("Treasury account does not match config")]
+    InvalidTreasury,
+
+    #[msg("Unstake already requested (position is unbonding)")]
+    AlreadyUnbonding,
+
+    #[msg("Unstake has not been requested")]
+    NotUnbonding,
+
+    #[msg("Unbonding period has not ended")]
+    UnbondingNotComplete,
+
+    #[msg("Invalid staking config parameters")]
+    InvalidConfig,
+
+    #[msg("Vault does not match staking config")]
+    InvalidVault,
+
+    #[msg("Token mint does not match staking config")]
+    InvalidMint,
+
+    #[msg("Stake position does not belong to this staker")]
+    PositionMismatch,
+
+    #[msg("All active stake positions must be provided exactly once")]
+    IncompletePositions,
+
+    #[msg("Node delegation account does not match position")]
+    DelegationMismatch,
+
+    #[msg("Position is already delegated")]
+    AlreadyDelegated,
+
+    #[msg("Position is not delegated")]
+    NotDelegated,
+
+    #[msg("Position is delegated (undelegate first)")]
+    PositionDelegated,
+
+    #[msg("Commission must be <= 10000 bps (100%)")]
+    InvalidCommission,
+
+    #[msg("Only the node operator can perform this action")]
+    UnauthorizedOperator,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidSchedule,
+
+    #[msg("Vesting grant account missing or does not match position")]
+    GrantMismatch,
+
+    #[msg("Insufficient unstaked grant balance")]
+    InsufficientGrantBalance,
+
+    #[msg("Nothing vested to claim")]
+    NothingToClaim,
+
+    #[msg("Grant-funded positions cannot be topped up")]
+    GrantPosition,
+
+    #[msg("Node still has delegated stake")]
+    DelegationNotEmpty,
+
+    #[msg("Reward pool accounts do not match the position's node")]
+    NodeRewardsMismatch,
 }
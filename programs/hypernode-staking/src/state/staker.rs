@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+use crate::state::{StakeAccount, StakeTier, StakingConfig, StakingError, TierThresholds};
+
+/// Per-wallet aggregate of all active stake positions
+///
+/// Each wallet can hold many independent `StakeAccount` positions
+/// (different amounts, durations and unlocks). This account sums them so
+/// consumers (rewards, market gating) read a single xNOS value.
+#[account]
+pub struct Staker {
+    /// Wallet owning the positions
+    pub authority: Pubkey,
+
+    /// Total xNOS across active positions
+    pub xnos: u64,
+
+    /// Part of `xnos` delegated to nodes (counted in their `NodeDelegation`)
+    pub delegated_xnos: u64,
+
+    /// Total HYPER staked across active positions
+    pub staked_amount: u64,
+
+    /// Tier derived from aggregate xNOS
+    pub tier: StakeTier,
+
+    /// Number of positions not yet withdrawn
+    pub active_positions: u32,
+
+    /// Id to use for the next position (monotonic, never reused)
+    pub next_position_id: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Staker {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // authority
+        8 + // xnos
+        8 + // delegated_xnos
+        8 + // staked_amount
+        1 + // tier
+        4 + // active_positions
+        8 + // next_position_id
+        1; // bump
+
+    /// Add a position's amount and xNOS to the aggregate
+    pub fn credit(
+        &mut self,
+        amount: u64,
+        xnos: u64,
+        thresholds: &TierThresholds,
+    ) -> Result<()> {
+        self.staked_amount = self
+            .staked_amount
+            .checked_add(amount)
+            .ok_or(StakingError::CalculationOverflow)?;
+        self.xnos = self
+            .xnos
+            .checked_add(xnos)
+            .ok_or(StakingError::CalculationOverflow)?;
+        self.tier = StakeAccount::calculate_tier(self.xnos, thresholds);
+        Ok(())
+    }
+
+    /// Remove a position's amount and xNOS from the aggregate
+    pub fn debit(
+        &mut self,
+        amount: u64,
+        xnos: u64,
+        thresholds: &TierThresholds,
+    ) -> Result<()> {
+        self.staked_amount = self
+            .staked_amount
+            .checked_sub(amount)
+            .ok_or(StakingError::CalculationOverflow)?;
+        self.xnos = self
+            .xnos
+            .checked_sub(xnos)
+            .ok_or(StakingError::CalculationOverflow)?;
+        self.tier = StakeAccount::calculate_tier(self.xnos, thresholds);
+        Ok(())
+    }
+
+    /// xNOS of positions not delegated to any node
+    ///
+    /// What the wallet backs its own nodes with: delegated positions already
+    /// count in the target node's `NodeDelegation`, including self-delegation.
+    pub fn own_xnos(&self) -> u64 {
+        self.xnos.saturating_sub(self.delegated_xnos)
+    }
+
+    /// Replace a delegated position's old xNOS with its new value
+    pub fn rebalance_delegated(&mut self, old_xnos: u64, new_xnos: u64) -> Result<()> {
+        self.delegated_xnos = self
+            .delegated_xnos
+            .checked_sub(old_xnos)
+            .and_then(|v| v.checked_add(new_xnos))
+            .ok_or(StakingError::CalculationOverflow)?;
+        Ok(())
+    }
+
+    /// Sum of effective xNOS across this staker's positions at a timestamp
+    ///
+    /// `positions` must contain every non-withdrawn position exactly once
+    /// (withdrawn ones may be included and count as zero). Each position is
+    /// taken as it was at `at_timestamp` (see `StakeAccount::at`): positions
+    /// opened later count as zero and later topups, extends or restakes are
+    /// ignored, so past timestamps act as snapshots. Uses the config's decay
+    /// mode, so callers get static or vote-escrow xNOS transparently.
+    pub fn voting_power<'info>(
+        &self,
+        config: &StakingConfig,
+        positions: &'info [AccountInfo<'info>],
+        at_timestamp: i64,
+    ) -> Result<u64> {
+        let mut seen = BTreeSet::new();
+        let mut active: u32 = 0;
+        let mut voting_power: u64 = 0;
+
+        for account_info in positions.iter() {
+            let stake_account = Account::<StakeAccount>::try_from(account_info)?;
+
+            require!(
+                stake_account.authority == self.authority,
+                StakingError::PositionMismatch
+            );
+            require!(
+                seen.insert(stake_account.position_id),
+                StakingError::IncompletePositions
+            );
+
+            if stake_account.withdrawn {
+                continue;
+            }
+            active = active.saturating_add(1);
+
+            if stake_account.staked_at > at_timestamp {
+                continue;
+            }
+
+            let position = stake_account.at(at_timestamp);
+            voting_power = voting_power
+                .checked_add(config.effective_xnos(&position, at_timestamp))
+                .ok_or(StakingError::CalculationOverflow)?;
+        }
+
+        require!(
+            active == self.active_positions,
+            StakingError::IncompletePositions
+        );
+
+        Ok(voting_power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staker() -> Staker {
+        Staker {
+            authority: Pubkey::new_unique(),
+            xnos: 0,
+            delegated_xnos: 0,
+            staked_amount: 0,
+            tier: StakeTier::Starter,
+            active_positions: 0,
+            next_position_id: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn self_delegation_is_not_counted_twice() {
+        let thresholds = TierThresholds {
+            bronze: 10_000,
+            silver: 20_000,
+            gold: 30_000,
+            diamond: 40_000,
+        };
+        let mut operator = staker();
+        operator.credit(1_000, 1_500, &thresholds).unwrap();
+        assert_eq!(operator.own_xnos(), 1_500);
+
+        // Operator delegates its only position to its own node
+        operator.rebalance_delegated(0, 1_500).unwrap();
+        let node_delegated_xnos = 1_500;
+        assert_eq!(operator.own_xnos(), 0);
+        assert_eq!(operator.own_xnos() + node_delegated_xnos, 1_500);
+
+        // Topup of the delegated position moves both by the same delta
+        operator.debit(0, 1_500, &thresholds).unwrap();
+        operator.credit(500, 2_250, &thresholds).unwrap();
+        operator.rebalance_delegated(1_500, 2_250).unwrap();
+        assert_eq!(operator.own_xnos(), 0);
+
+        // Undelegating returns it to the operator's own stake
+        operator.rebalance_delegated(2_250, 0).unwrap();
+        assert_eq!(operator.own_xnos(), 2_250);
+    }
+}
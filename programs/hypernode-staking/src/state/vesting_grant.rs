@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakeAccount, StakingError};
+
+/// Locked token grant vesting with a cliff, then linearly
+///
+/// Granted tokens sit in the stake vault. The beneficiary can stake the
+/// unstaked part (`stake_grant`); withdrawing such a position returns the
+/// tokens to the grant instead of the wallet. Only vested tokens leave the
+/// vault, through `claim_vested`. Vesting runs on wall-clock time,
+/// regardless of how much is staked.
+#[account]
+pub struct VestingGrant {
+    /// Wallet receiving vested tokens (and staking the grant)
+    pub beneficiary: Pubkey,
+
+    /// Wallet that funded the grant
+    pub funder: Pubkey,
+
+    /// Grant id (PDA seed, per beneficiary)
+    pub grant_id: u64,
+
+    /// Tokens granted (reduced by slashes on grant-funded positions)
+    pub total_amount: u64,
+
+    /// Vested tokens already claimed
+    pub released: u64,
+
+    /// Tokens currently in grant-funded stake positions
+    pub staked: u64,
+
+    /// Vesting start
+    pub start_ts: i64,
+
+    /// Nothing vests before the cliff
+    pub cliff_ts: i64,
+
+    /// Fully vested at end
+    pub end_ts: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VestingGrant {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // beneficiary
+        32 + // funder
+        8 + // grant_id
+        8 + // total_amount
+        8 + // released
+        8 + // staked
+        8 + // start_ts
+        8 + // cliff_ts
+        8 + // end_ts
+        1; // bump
+
+    /// Validate a schedule: start <= cliff <= end, start < end
+    pub fn is_valid_schedule(start_ts: i64, cliff_ts: i64, end_ts: i64) -> bool {
+        start_ts <= cliff_ts && cliff_ts <= end_ts && start_ts < end_ts
+    }
+
+    /// Tokens vested at a timestamp (cliff + linear)
+    pub fn vested_amount(&self, at_timestamp: i64) -> u64 {
+        if at_timestamp < self.cliff_ts {
+            return 0;
+        }
+        if at_timestamp >= self.end_ts {
+            return self.total_amount;
+        }
+
+        let elapsed = (at_timestamp - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Granted tokens in the vault but not in a stake position
+    pub fn unstaked(&self) -> u64 {
+        self.total_amount
+            .saturating_sub(self.released)
+            .saturating_sub(self.staked)
+    }
+
+    /// Vested, unclaimed tokens that are not staked
+    pub fn claimable(&self, at_timestamp: i64) -> u64 {
+        self.vested_amount(at_timestamp)
+            .saturating_sub(self.released)
+            .min(self.unstaked())
+    }
+}
+
+/// Apply a grant-funded position's amount change to its grant
+///
+/// Call before updating `stake_account.staked_amount`. Decreases (slashes)
+/// are lost from the grant; positions without a grant are ignored.
+pub fn sync_grant(
+    grant: Option<&mut Account<VestingGrant>>,
+    stake_account: &StakeAccount,
+    new_amount: u64,
+) -> Result<()> {
+    let Some(grant_key) = stake_account.grant else {
+        return Ok(());
+    };
+
+    let grant = grant.ok_or(StakingError::GrantMismatch)?;
+    require!(grant.key() == grant_key, StakingError::GrantMismatch);
+
+    let lost = stake_account.staked_amount.saturating_sub(new_amount);
+    grant.staked = grant
+        .staked
+        .checked_sub(lost)
+        .ok_or(StakingError::CalculationOverflow)?;
+    grant.total_amount = grant
+        .total_amount
+        .checked_sub(lost)
+        .ok_or(StakingError::CalculationOverflow)?;
+
+    Ok(())
+}
@@ -28,4 +28,31 @@ pub enum NodeRegistryError {
 
     #[msg("Heartbeat interval too short (minimum 30 seconds)")]
     HeartbeatTooFrequent,
+
+    #[msg("Unauthorized: not registry admin")]
+    UnauthorizedNotAdmin,
+
+    #[msg("Unauthorized: caller is not the configured job authority")]
+    UnauthorizedCaller,
+
+    #[msg("Token mint is not HYPER")]
+    InvalidMint,
+
+    #[msg("Unstake cooldown must not be negative")]
+    InvalidCooldown,
+
+    #[msg("An unstake is already pending")]
+    UnstakePending,
+
+    #[msg("No unstake pending")]
+    NothingToUnstake,
+
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldownActive,
+
+    #[msg("Node still has stake; unstake and withdraw first")]
+    StakeNotWithdrawn,
+
+    #[msg("Legacy registration is closed; register in hypernode-nodes")]
+    RegistrationClosed,
 }
@@ -1,18 +1,27 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::errors::NodeRegistryError;
 
 #[derive(Accounts)]
 pub struct AddEarnings<'info> {
     #[account(mut)]
     pub node_account: Account<'info, NodeAccount>,
 
-    // In production, called by Payment Splitter via CPI
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Job program PDA (signs via CPI)
+    #[account(address = config.job_authority @ NodeRegistryError::UnauthorizedCaller)]
+    pub job_authority: Signer<'info>,
 }
 
 pub fn add_earnings(ctx: Context<AddEarnings>, amount: u64) -> Result<()> {
     let node_account = &mut ctx.accounts.node_account;
 
-    node_account.total_earned = node_account.total_earned.checked_add(amount).unwrap();
+    node_account.add_earnings(amount);
 
     Ok(())
 }
@@ -19,6 +19,12 @@ pub fn deregister_node(ctx: Context<DeregisterNode>) -> Result<()> {
     let node_account = &ctx.accounts.node_account;
     let clock = Clock::get()?;
 
+    // Stake must be withdrawn first, or it would be stranded in the vault
+    require!(
+        node_account.stake_amount == 0 && node_account.unstaking_amount == 0,
+        NodeRegistryError::StakeNotWithdrawn
+    );
+
     emit!(NodeDeregistered {
         node_id: node_account.node_id.clone(),
         owner: node_account.owner,
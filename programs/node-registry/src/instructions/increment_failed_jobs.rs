@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+
+#[derive(Accounts)]
+pub struct IncrementFailedJobs<'info> {
+    #[account(mut)]
+    pub node_account: Account<'info, NodeAccount>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Job program PDA (signs via CPI)
+    #[account(address = config.job_authority @ NodeRegistryError::UnauthorizedCaller)]
+    pub job_authority: Signer<'info>,
+}
+
+pub fn increment_failed_jobs(ctx: Context<IncrementFailedJobs>) -> Result<()> {
+    let node_account = &mut ctx.accounts.node_account;
+
+    node_account.increment_failed_jobs();
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+use crate::program::NodeRegistry;
+
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = RegistryConfig::LEN,
+        seeds = [b"registry_config"],
+        bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// HYPER mint accepted for node stake
+    pub hyper_mint: Account<'info, Mint>,
+
+    /// Vault holding all node stake (owned by the config PDA)
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"stake_vault"],
+        bump,
+        token::mint = hyper_mint,
+        token::authority = config
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// Program upgrade authority (becomes the registry admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ NodeRegistryError::UnauthorizedNotAdmin
+    )]
+    pub program: Program<'info, NodeRegistry>,
+
+    /// Upgrade data of this program (holds the upgrade authority)
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ NodeRegistryError::UnauthorizedNotAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Create the registry config: the job program PDA trusted to record job
+/// outcomes and earnings, and the HYPER stake vault
+///
+/// Only the program's upgrade authority may initialize (and become admin),
+/// so the config PDA can't be claimed by whoever calls first after deploy.
+pub fn initialize_registry(
+    ctx: Context<InitializeRegistry>,
+    job_authority: Pubkey,
+    unstake_cooldown: i64,
+) -> Result<()> {
+    require!(unstake_cooldown >= 0, NodeRegistryError::InvalidCooldown);
+
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.job_authority = job_authority;
+    config.hyper_mint = ctx.accounts.hyper_mint.key();
+    config.stake_vault = ctx.accounts.stake_vault.key();
+    config.unstake_cooldown = unstake_cooldown;
+    config.registration_open = false;
+    config.bump = ctx.bumps.config;
+
+    emit!(JobAuthoritySet {
+        job_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct JobAuthoritySet {
+    pub job_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+
+#[derive(Accounts)]
+pub struct MigrateOut<'info> {
+    #[account(
+        mut,
+        has_one = owner @ NodeRegistryError::UnauthorizedNotOwner,
+        close = owner
+    )]
+    pub node_account: Account<'info, NodeAccount>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// hypernode-nodes legacy stake vault (receives all escrowed stake)
+    #[account(
+        mut,
+        constraint = destination.mint == config.hyper_mint @ NodeRegistryError::InvalidMint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// Registry stake vault
+    #[account(
+        mut,
+        address = config.stake_vault
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// hypernode-nodes migration PDA (signs via CPI)
+    #[account(
+        seeds = [b"migration_authority"],
+        bump,
+        seeds::program = nodes_program::ID
+    )]
+    pub migration_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Move a migrating node's stake out and close its legacy account
+///
+/// Only callable by hypernode-nodes `migrate_from_legacy`, which picks the
+/// destination (its legacy stake vault). Staked and unstaking HYPER both
+/// move; hypernode-nodes keeps them apart and enforces the cooldown of the
+/// unstaking part (`unstake_available_at`).
+pub fn migrate_out(ctx: Context<MigrateOut>) -> Result<()> {
+    let node_account = &ctx.accounts.node_account;
+    let clock = Clock::get()?;
+
+    let amount = node_account
+        .stake_amount
+        .saturating_add(node_account.unstaking_amount);
+
+    if amount > 0 {
+        // Transfer from vault (config PDA signs)
+        let seeds = &[b"registry_config".as_ref(), &[ctx.accounts.config.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.stake_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    emit!(NodeMigrated {
+        node_id: node_account.node_id.clone(),
+        owner: node_account.owner,
+        stake_moved: amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Account will be closed automatically due to close constraint
+    Ok(())
+}
+
+#[event]
+pub struct NodeMigrated {
+    pub node_id: String,
+    pub owner: Pubkey,
+    pub stake_moved: u64,
+    pub timestamp: i64,
+}
@@ -1,15 +1,29 @@
 pub mod register_node;
 pub mod update_node_status;
 pub mod stake_for_node;
+pub mod request_unstake_from_node;
+pub mod unstake_from_node;
 pub mod heartbeat;
 pub mod deregister_node;
+pub mod migrate_out;
+pub mod initialize_registry;
+pub mod set_job_authority;
+pub mod set_registration_open;
 pub mod increment_jobs_completed;
+pub mod increment_failed_jobs;
 pub mod add_earnings;
 
 pub use register_node::*;
 pub use update_node_status::*;
 pub use stake_for_node::*;
+pub use request_unstake_from_node::*;
+pub use unstake_from_node::*;
 pub use heartbeat::*;
 pub use deregister_node::*;
+pub use migrate_out::*;
+pub use initialize_registry::*;
+pub use set_job_authority::*;
+pub use set_registration_open::*;
 pub use increment_jobs_completed::*;
+pub use increment_failed_jobs::*;
 pub use add_earnings::*;
@@ -14,18 +14,32 @@ pub struct RegisterNode<'info> {
     )]
     pub node_account: Account<'info, NodeAccount>,
 
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     #[account(mut)]
     pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Register a legacy node (only while the admin keeps registration open)
+///
+/// New nodes belong in hypernode-nodes; registration is closed from
+/// `initialize_registry` on and only reopened for maintenance or tests.
 pub fn register_node(
     ctx: Context<RegisterNode>,
     node_id: String,
     gpu_specs_hash: String,
     location: String,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.config.registration_open,
+        NodeRegistryError::RegistrationClosed
+    );
     require!(node_id.len() <= 64, NodeRegistryError::NodeIdTooLong);
     require!(gpu_specs_hash.len() <= 64, NodeRegistryError::InvalidGpuSpecsHash);
     require!(location.len() <= 64, NodeRegistryError::LocationTooLong);
@@ -41,8 +55,11 @@ pub fn register_node(
     node_account.last_heartbeat = clock.unix_timestamp;
     node_account.status = NodeStatus::Online;
     node_account.stake_amount = 0;
+    node_account.unstaking_amount = 0;
+    node_account.unstake_available_at = 0;
     node_account.reputation_score = 100; // Starting reputation
     node_account.jobs_completed = 0;
+    node_account.jobs_failed = 0;
     node_account.total_earned = 0;
     node_account.bump = ctx.bumps.node_account;
 
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+
+#[derive(Accounts)]
+pub struct RequestUnstakeFromNode<'info> {
+    #[account(
+        mut,
+        has_one = owner @ NodeRegistryError::UnauthorizedNotOwner
+    )]
+    pub node_account: Account<'info, NodeAccount>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Start unstaking HYPER from a node
+///
+/// The amount stops counting toward reputation immediately and can be
+/// withdrawn with `unstake_from_node` once the cooldown has elapsed.
+pub fn request_unstake_from_node(
+    ctx: Context<RequestUnstakeFromNode>,
+    amount: u64,
+) -> Result<()> {
+    let node_account = &mut ctx.accounts.node_account;
+    let clock = Clock::get()?;
+
+    require!(
+        amount > 0 && amount <= node_account.stake_amount,
+        NodeRegistryError::InsufficientStake
+    );
+    require!(
+        node_account.unstaking_amount == 0,
+        NodeRegistryError::UnstakePending
+    );
+
+    node_account.stake_amount -= amount;
+    node_account.unstaking_amount = amount;
+    node_account.unstake_available_at = clock
+        .unix_timestamp
+        .saturating_add(ctx.accounts.config.unstake_cooldown);
+    node_account.update_reputation();
+
+    emit!(NodeUnstakeRequested {
+        node_id: node_account.node_id.clone(),
+        amount,
+        available_at: node_account.unstake_available_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NodeUnstakeRequested {
+    pub node_id: String,
+    pub amount: u64,
+    pub available_at: i64,
+    pub timestamp: i64,
+}
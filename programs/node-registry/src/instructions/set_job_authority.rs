@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+use crate::instructions::initialize_registry::JobAuthoritySet;
+
+#[derive(Accounts)]
+pub struct SetJobAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_config"],
+        bump = config.bump,
+        has_one = admin @ NodeRegistryError::UnauthorizedNotAdmin
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Change the job program PDA trusted to record job outcomes (admin only)
+pub fn set_job_authority(ctx: Context<SetJobAuthority>, job_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.job_authority = job_authority;
+
+    emit!(JobAuthoritySet {
+        job_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+
+#[derive(Accounts)]
+pub struct SetRegistrationOpen<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_config"],
+        bump = config.bump,
+        has_one = admin @ NodeRegistryError::UnauthorizedNotAdmin
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Open or close `register_node` and `stake_for_node` (admin only)
+pub fn set_registration_open(ctx: Context<SetRegistrationOpen>, open: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.registration_open = open;
+
+    emit!(RegistrationOpenSet {
+        open,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RegistrationOpenSet {
+    pub open: bool,
+    pub timestamp: i64,
+}
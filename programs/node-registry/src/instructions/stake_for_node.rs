@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::errors::NodeRegistryError;
 
@@ -10,34 +11,74 @@ pub struct StakeForNode<'info> {
     )]
     pub node_account: Account<'info, NodeAccount>,
 
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    // In a real implementation, would transfer SPL tokens here
-    // pub token_account: Account<'info, TokenAccount>,
-    // pub hyper_mint: Account<'info, Mint>,
-    // pub token_program: Program<'info, Token>,
+    /// Owner's HYPER token account
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == config.hyper_mint @ NodeRegistryError::InvalidMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// Registry stake vault
+    #[account(
+        mut,
+        address = config.stake_vault
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
 }
 
+/// Escrow HYPER in the registry vault as stake for a node
+///
+/// Reputation gets a bonus from the escrowed amount. Like registration,
+/// only while the admin keeps registration open.
 pub fn stake_for_node(
     ctx: Context<StakeForNode>,
     amount: u64,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.config.registration_open,
+        NodeRegistryError::RegistrationClosed
+    );
     require!(amount > 0, NodeRegistryError::InsufficientStake);
 
-    let node_account = &mut ctx.accounts.node_account;
+    // Transfer HYPER tokens to stake vault
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
 
-    // In real implementation: transfer tokens to PDA
-    // For MVP, just increment the stake_amount
+    // Update node stake amount
+    let node_account = &mut ctx.accounts.node_account;
     node_account.stake_amount = node_account.stake_amount.checked_add(amount).unwrap();
+    node_account.update_reputation();
 
-    // Increase reputation based on stake (simple linear model)
-    // Each 1000 tokens staked = +10 reputation (max 1000)
-    let reputation_increase = ((amount / 1_000_000_000) * 10) as u16; // Assuming 9 decimals
-    node_account.reputation_score = std::cmp::min(
-        1000,
-        node_account.reputation_score.saturating_add(reputation_increase)
-    );
+    emit!(NodeStakedEvent {
+        node_id: node_account.node_id.clone(),
+        amount,
+        total_stake: node_account.stake_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
+
+#[event]
+pub struct NodeStakedEvent {
+    pub node_id: String,
+    pub amount: u64,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::NodeRegistryError;
+
+#[derive(Accounts)]
+pub struct UnstakeFromNode<'info> {
+    #[account(
+        mut,
+        has_one = owner @ NodeRegistryError::UnauthorizedNotOwner
+    )]
+    pub node_account: Account<'info, NodeAccount>,
+
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub owner: Signer<'info>,
+
+    /// Owner's HYPER token account (destination)
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == config.hyper_mint @ NodeRegistryError::InvalidMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// Registry stake vault
+    #[account(
+        mut,
+        address = config.stake_vault
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw HYPER requested with `request_unstake_from_node` after the
+/// cooldown
+pub fn unstake_from_node(ctx: Context<UnstakeFromNode>) -> Result<()> {
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.node_account.unstaking_amount;
+
+    require!(amount > 0, NodeRegistryError::NothingToUnstake);
+    require!(
+        clock.unix_timestamp >= ctx.accounts.node_account.unstake_available_at,
+        NodeRegistryError::UnstakeCooldownActive
+    );
+
+    // Transfer from vault (config PDA signs)
+    let seeds = &[b"registry_config".as_ref(), &[ctx.accounts.config.bump]];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    let node_account = &mut ctx.accounts.node_account;
+    node_account.unstaking_amount = 0;
+    node_account.unstake_available_at = 0;
+
+    emit!(NodeUnstaked {
+        node_id: node_account.node_id.clone(),
+        amount,
+        remaining_stake: node_account.stake_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NodeUnstaked {
+    pub node_id: String,
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+}
@@ -9,11 +9,18 @@ use state::*;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
+/// Legacy node registry
+///
+/// Superseded by hypernode-nodes, the canonical registry: new nodes and
+/// new stake are only accepted while the admin opens registration (closed
+/// by default). Existing nodes move there with
+/// `hypernode_nodes::migrate_from_legacy`, which calls `migrate_out` here
+/// to move their stake into hypernode-nodes and close the legacy account.
 #[program]
 pub mod node_registry {
     use super::*;
 
-    /// Register a new GPU/CPU node on-chain
+    /// Register a legacy node (registration open only)
     pub fn register_node(
         ctx: Context<RegisterNode>,
         node_id: String,
@@ -31,7 +38,7 @@ pub mod node_registry {
         instructions::update_node_status(ctx, status)
     }
 
-    /// Stake HYPER tokens to increase reputation
+    /// Escrow HYPER tokens as node stake (registration open only)
     pub fn stake_for_node(
         ctx: Context<StakeForNode>,
         amount: u64,
@@ -39,24 +46,74 @@ pub mod node_registry {
         instructions::stake_for_node(ctx, amount)
     }
 
+    /// Start the unstake cooldown for part of a node's stake
+    pub fn request_unstake_from_node(
+        ctx: Context<RequestUnstakeFromNode>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::request_unstake_from_node(ctx, amount)
+    }
+
+    /// Withdraw unstaked HYPER after the cooldown
+    pub fn unstake_from_node(ctx: Context<UnstakeFromNode>) -> Result<()> {
+        instructions::unstake_from_node(ctx)
+    }
+
     /// Send heartbeat to show node is still active
     pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
         instructions::heartbeat(ctx)
     }
 
+    /// Release stake and close a node migrating to hypernode-nodes (CPI only)
+    pub fn migrate_out(ctx: Context<MigrateOut>) -> Result<()> {
+        instructions::migrate_out(ctx)
+    }
+
     /// Deregister node from the network
     pub fn deregister_node(ctx: Context<DeregisterNode>) -> Result<()> {
         instructions::deregister_node(ctx)
     }
 
-    /// Increment jobs completed counter
+    /// Create the registry config (upgrade authority only)
+    pub fn initialize_registry(
+        ctx: Context<InitializeRegistry>,
+        job_authority: Pubkey,
+        unstake_cooldown: i64,
+    ) -> Result<()> {
+        instructions::initialize_registry(ctx, job_authority, unstake_cooldown)
+    }
+
+    /// Change the trusted job program PDA (admin only)
+    pub fn set_job_authority(
+        ctx: Context<SetJobAuthority>,
+        job_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_job_authority(ctx, job_authority)
+    }
+
+    /// Open or close legacy registration and staking (admin only)
+    pub fn set_registration_open(
+        ctx: Context<SetRegistrationOpen>,
+        open: bool,
+    ) -> Result<()> {
+        instructions::set_registration_open(ctx, open)
+    }
+
+    /// Increment jobs completed counter (job authority CPI only)
     pub fn increment_jobs_completed(
         ctx: Context<IncrementJobsCompleted>,
     ) -> Result<()> {
         instructions::increment_jobs_completed(ctx)
     }
 
-    /// Add earnings to node account
+    /// Increment jobs failed counter (job authority CPI only)
+    pub fn increment_failed_jobs(
+        ctx: Context<IncrementFailedJobs>,
+    ) -> Result<()> {
+        instructions::increment_failed_jobs(ctx)
+    }
+
+    /// Add earnings to node account (job authority CPI only)
     pub fn add_earnings(
         ctx: Context<AddEarnings>,
         amount: u64,
@@ -1,3 +1,5 @@
 pub mod node_account;
+pub mod registry_config;
 
 pub use node_account::*;
+pub use registry_config::*;
@@ -1,18 +1,20 @@
 use anchor_lang::prelude::*;
+use hypernode_reputation::ReputationInputs;
 
+/// Node account structure stored on-chain
 #[account]
 #[derive(Default)]
 pub struct NodeAccount {
-    /// Node owner wallet public key
+    /// Node operator's wallet address
     pub owner: Pubkey,
 
-    /// Unique node identifier (max 32 chars)
+    /// Unique node identifier (UUID)
     pub node_id: String,
 
-    /// Hash of GPU specifications
+    /// Hash of GPU specifications (for verification)
     pub gpu_specs_hash: String,
 
-    /// Geographic location hint
+    /// Approximate location (country/city)
     pub location: String,
 
     /// Unix timestamp of registration
@@ -21,12 +23,16 @@ pub struct NodeAccount {
     /// Unix timestamp of last heartbeat
     pub last_heartbeat: i64,
 
-    /// Node status (0=offline, 1=online, 2=suspended)
-    pub status: u8,
+    /// Current node status
+    pub status: NodeStatus,
 
-    /// Amount of HYPER staked (in lamports)
+    /// HYPER escrowed in the stake vault for this node (in lamports)
     pub stake_amount: u64,
 
+    /// HYPER leaving stake, withdrawable after `unstake_available_at`
+    pub unstaking_amount: u64,
+    pub unstake_available_at: i64,
+
     /// Reputation score (0-1000)
     pub reputation_score: u16,
 
@@ -44,15 +50,18 @@ pub struct NodeAccount {
 }
 
 impl NodeAccount {
+    /// Calculate space needed for account
     pub const LEN: usize = 8 + // discriminator
         32 + // owner
-        (4 + 32) + // node_id
-        (4 + 64) + // gpu_specs_hash
-        (4 + 32) + // location
+        4 + 64 + // node_id (max 64 chars)
+        4 + 64 + // gpu_specs_hash
+        4 + 64 + // location
         8 + // registered_at
         8 + // last_heartbeat
         1 + // status
         8 + // stake_amount
+        8 + // unstaking_amount
+        8 + // unstake_available_at
         2 + // reputation_score
         8 + // jobs_completed
         8 + // jobs_failed
@@ -60,11 +69,11 @@ impl NodeAccount {
         1; // bump
 
     pub fn is_online(&self) -> bool {
-        self.status == 1
+        self.status == NodeStatus::Online
     }
 
     pub fn is_suspended(&self) -> bool {
-        self.status == 2
+        self.status == NodeStatus::Suspended
     }
 
     pub fn increment_completed_jobs(&mut self) {
@@ -81,27 +90,32 @@ impl NodeAccount {
         self.total_earned = self.total_earned.saturating_add(amount);
     }
 
+    /// Recompute reputation with the shared fixed-point formula
+    ///
+    /// Only job outcomes are tracked here; health and uptime inputs are
+    /// neutral. Escrowed stake (not counting HYPER being unstaked) adds
+    /// 1 point per 1 HYPER (9 decimals), up to 100.
     pub fn update_reputation(&mut self) {
-        let total_jobs = self.jobs_completed.saturating_add(self.jobs_failed);
-        if total_jobs == 0 {
-            self.reputation_score = 500; // neutral starting score
-            return;
-        }
-
-        // Simple reputation: (completed / total) * 1000
-        let success_rate = (self.jobs_completed as f64 / total_jobs as f64) * 1000.0;
-
-        // Bonus for stake (up to 10% boost)
-        let stake_bonus = (self.stake_amount as f64 / 1_000_000_000.0).min(100.0);
-
-        let final_score = (success_rate + stake_bonus).min(1000.0);
-        self.reputation_score = final_score as u16;
+        let base = hypernode_reputation::score(&ReputationInputs {
+            jobs_completed: self.jobs_completed,
+            jobs_failed: self.jobs_failed,
+            uptime_percentage: 50,
+            ..ReputationInputs::default()
+        });
+
+        let stake_bonus = (self.stake_amount / 1_000_000_000).min(100) as u16;
+
+        self.reputation_score = base
+            .saturating_add(stake_bonus)
+            .min(hypernode_reputation::MAX_SCORE);
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+/// Node status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum NodeStatus {
-    Offline = 0,
-    Online = 1,
-    Suspended = 2,
+    Online,
+    #[default]
+    Offline,
+    Suspended,
 }
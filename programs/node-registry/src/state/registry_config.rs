@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// hypernode-nodes program (canonical registry, migrates nodes out of here)
+///
+/// Hardcoded because hypernode-nodes depends on this crate.
+pub mod nodes_program {
+    anchor_lang::declare_id!("HYPRnodes11111111111111111111111111111111111");
+}
+
+/// Global registry configuration
+///
+/// Lists the trusted caller allowed to record job outcomes and earnings
+/// (a PDA of the job program that signs via CPI), and the HYPER stake
+/// vault the config PDA controls.
+#[account]
+pub struct RegistryConfig {
+    /// Admin (can change the job authority and open registration)
+    pub admin: Pubkey,
+
+    /// Job program PDA that must sign job/earnings updates
+    pub job_authority: Pubkey,
+
+    /// HYPER mint accepted for node stake
+    pub hyper_mint: Pubkey,
+
+    /// Token vault holding all node stake (authority = this config)
+    pub stake_vault: Pubkey,
+
+    /// Seconds between requesting an unstake and withdrawing it
+    pub unstake_cooldown: i64,
+
+    /// Whether `register_node` and `stake_for_node` are accepted (closed by
+    /// default: new nodes register in hypernode-nodes)
+    pub registration_open: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RegistryConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        32 + // job_authority
+        32 + // hyper_mint
+        32 + // stake_vault
+        8 + // unstake_cooldown
+        1 + // registration_open
+        1; // bump
+}
@@ -1,119 +1,371 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import {
+  createMint,
+  createAccount,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
 import { NodeRegistry } from "../target/types/node_registry";
+import { HypernodeNodes } from "../target/types/hypernode_nodes";
 import { assert } from "chai";
 
+const { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } = anchor.web3;
+
+const BPF_LOADER_UPGRADEABLE = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// Short cooldown so unstake paths can be exercised in one run
+const UNSTAKE_COOLDOWN = 2;
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+async function expectError(promise: Promise<unknown>, code: string) {
+  try {
+    await promise;
+  } catch (err) {
+    assert.equal(err.error?.errorCode?.code, code);
+    return;
+  }
+  assert.fail(`expected ${code}`);
+}
+
 describe("node-registry", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = anchor.workspace.NodeRegistry as Program<NodeRegistry>;
+  const nodesProgram = anchor.workspace
+    .HypernodeNodes as Program<HypernodeNodes>;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const owner = provider.wallet.publicKey;
 
-  it("Registers a new node", async () => {
-    const nodeId = "test-node-" + Date.now();
-    const gpuSpecsHash = "hash_nvidia_rtx_4090_24gb";
-    const location = "us-east-1";
-
-    const [nodeAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("node"),
-        provider.wallet.publicKey.toBuffer(),
-        Buffer.from(nodeId),
-      ],
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("registry_config")],
+    program.programId
+  );
+  const [stakeVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_vault")],
+    program.programId
+  );
+
+  let hyperMint: anchor.web3.PublicKey;
+  let ownerTokenAccount: anchor.web3.PublicKey;
+
+  const nodeAccountFor = (nodeId: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("node"), owner.toBuffer(), Buffer.from(nodeId)],
       program.programId
-    );
+    )[0];
 
-    await program.methods
-      .registerNode(nodeId, gpuSpecsHash, location)
+  const register = (nodeId: string) =>
+    program.methods
+      .registerNode(nodeId, "hash_nvidia_rtx_4090_24gb", "us-east-1")
       .accounts({
-        nodeAccount,
-        owner: provider.wallet.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
+        nodeAccount: nodeAccountFor(nodeId),
+        config,
+        owner,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
-    const node = await program.account.nodeAccount.fetch(nodeAccount);
+  const stake = (nodeId: string, amount: number) =>
+    program.methods
+      .stakeForNode(new anchor.BN(amount))
+      .accounts({
+        nodeAccount: nodeAccountFor(nodeId),
+        config,
+        owner,
+        ownerTokenAccount,
+        stakeVault,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      })
+      .rpc();
 
-    assert.equal(node.nodeId, nodeId);
-    assert.equal(node.gpuSpecsHash, gpuSpecsHash);
-    assert.equal(node.location, location);
-    assert.equal(node.status, 1); // Online
-    assert.equal(node.reputationScore, 500); // Neutral
-  });
+  const setRegistrationOpen = (open: boolean) =>
+    program.methods
+      .setRegistrationOpen(open)
+      .accounts({ config, admin: owner })
+      .rpc();
 
-  it("Updates node status", async () => {
-    const nodeId = "test-node-status-" + Date.now();
-
-    const [nodeAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("node"),
-        provider.wallet.publicKey.toBuffer(),
-        Buffer.from(nodeId),
-      ],
-      program.programId
+  before(async () => {
+    hyperMint = await createMint(provider.connection, payer, owner, null, 9);
+    ownerTokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      hyperMint,
+      owner
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      hyperMint,
+      ownerTokenAccount,
+      payer,
+      1_000_000
     );
 
-    // Register node first
+    // Upgrade authority (the provider wallet on localnet) initializes
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE
+    );
     await program.methods
-      .registerNode(nodeId, "hash", "location")
+      .initializeRegistry(owner, new anchor.BN(UNSTAKE_COOLDOWN))
       .accounts({
-        nodeAccount,
-        owner: provider.wallet.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
+        config,
+        hyperMint,
+        stakeVault,
+        admin: owner,
+        program: program.programId,
+        programData,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .rpc();
+  });
+
+  it("Starts with legacy registration closed", async () => {
+    const registry = await program.account.registryConfig.fetch(config);
+    assert.isFalse(registry.registrationOpen);
+
+    await expectError(register("closed-" + Date.now()), "RegistrationClosed");
+  });
+
+  it("Only the admin opens registration", async () => {
+    const other = anchor.web3.Keypair.generate();
+
+    await expectError(
+      program.methods
+        .setRegistrationOpen(true)
+        .accounts({ config, admin: other.publicKey })
+        .signers([other])
+        .rpc(),
+      "UnauthorizedNotAdmin"
+    );
+
+    await setRegistrationOpen(true);
+    const registry = await program.account.registryConfig.fetch(config);
+    assert.isTrue(registry.registrationOpen);
+  });
+
+  it("Registers a node", async () => {
+    const nodeId = "node-" + Date.now();
+    await register(nodeId);
+
+    const node = await program.account.nodeAccount.fetch(
+      nodeAccountFor(nodeId)
+    );
+    assert.equal(node.nodeId, nodeId);
+    assert.equal(node.gpuSpecsHash, "hash_nvidia_rtx_4090_24gb");
+    assert.equal(node.location, "us-east-1");
+    assert.deepEqual(node.status, { online: {} });
+    assert.equal(node.stakeAmount.toNumber(), 0);
+  });
+
+  it("Updates node status", async () => {
+    const nodeId = "status-" + Date.now();
+    await register(nodeId);
 
-    // Update status to offline (0)
     await program.methods
-      .updateNodeStatus(0)
-      .accounts({
-        nodeAccount,
-        owner: provider.wallet.publicKey,
-      })
+      .updateNodeStatus({ offline: {} })
+      .accounts({ nodeAccount: nodeAccountFor(nodeId), owner })
       .rpc();
 
-    const node = await program.account.nodeAccount.fetch(nodeAccount);
-    assert.equal(node.status, 0); // Offline
+    const node = await program.account.nodeAccount.fetch(
+      nodeAccountFor(nodeId)
+    );
+    assert.deepEqual(node.status, { offline: {} });
   });
 
-  it("Updates heartbeat", async () => {
-    const nodeId = "test-node-heartbeat-" + Date.now();
+  it("Rejects heartbeats less than 30 seconds apart", async () => {
+    const nodeId = "heartbeat-" + Date.now();
+    await register(nodeId);
 
-    const [nodeAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("node"),
-        provider.wallet.publicKey.toBuffer(),
-        Buffer.from(nodeId),
-      ],
-      program.programId
+    await expectError(
+      program.methods
+        .heartbeat()
+        .accounts({ nodeAccount: nodeAccountFor(nodeId), owner })
+        .rpc(),
+      "HeartbeatTooFrequent"
+    );
+  });
+
+  it("Stakes, unstakes after the cooldown and deregisters", async () => {
+    const nodeId = "stake-" + Date.now();
+    const nodeAccount = nodeAccountFor(nodeId);
+    await register(nodeId);
+    await stake(nodeId, 1_000);
+
+    let node = await program.account.nodeAccount.fetch(nodeAccount);
+    assert.equal(node.stakeAmount.toNumber(), 1_000);
+
+    // Stake must be withdrawn before the account can be closed
+    await expectError(
+      program.methods
+        .deregisterNode()
+        .accounts({ nodeAccount, owner })
+        .rpc(),
+      "StakeNotWithdrawn"
     );
 
     await program.methods
-      .registerNode(nodeId, "hash", "location")
-      .accounts({
-        nodeAccount,
-        owner: provider.wallet.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
+      .requestUnstakeFromNode(new anchor.BN(1_000))
+      .accounts({ nodeAccount, config, owner })
       .rpc();
 
-    const nodeBefore = await program.account.nodeAccount.fetch(nodeAccount);
-    const heartbeatBefore = nodeBefore.lastHeartbeat.toNumber();
+    node = await program.account.nodeAccount.fetch(nodeAccount);
+    assert.equal(node.stakeAmount.toNumber(), 0);
+    assert.equal(node.unstakingAmount.toNumber(), 1_000);
+
+    const unstake = () =>
+      program.methods
+        .unstakeFromNode()
+        .accounts({
+          nodeAccount,
+          config,
+          owner,
+          ownerTokenAccount,
+          stakeVault,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    await expectError(unstake(), "UnstakeCooldownActive");
 
-    // Wait a bit
-    await new Promise(resolve => setTimeout(resolve, 2000));
+    const before = await getAccount(provider.connection, ownerTokenAccount);
+    await sleep((UNSTAKE_COOLDOWN + 2) * 1000);
+    await unstake();
+    const after = await getAccount(provider.connection, ownerTokenAccount);
+    assert.equal(Number(after.amount - before.amount), 1_000);
 
     await program.methods
-      .updateHeartbeat()
+      .deregisterNode()
+      .accounts({ nodeAccount, owner })
+      .rpc();
+    assert.isNull(await provider.connection.getAccountInfo(nodeAccount));
+  });
+
+  it("Migrates a node to hypernode-nodes and withdraws its stake", async () => {
+    const nodeId = "migrate-" + Date.now();
+    const legacyNode = nodeAccountFor(nodeId);
+    await register(nodeId);
+    await stake(nodeId, 1_000);
+
+    // Part of the stake is already unbonding at migration
+    await program.methods
+      .requestUnstakeFromNode(new anchor.BN(300))
+      .accounts({ nodeAccount: legacyNode, config, owner })
+      .rpc();
+
+    const [node] = PublicKey.findProgramAddressSync(
+      [Buffer.from("node"), Buffer.from(nodeId)],
+      nodesProgram.programId
+    );
+    const [operator] = PublicKey.findProgramAddressSync(
+      [Buffer.from("operator"), owner.toBuffer()],
+      nodesProgram.programId
+    );
+    const [legacyStakeVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("legacy_stake_vault")],
+      nodesProgram.programId
+    );
+    const [migrationAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("migration_authority")],
+      nodesProgram.programId
+    );
+
+    await nodesProgram.methods
+      .migrateFromLegacy(nodeId, {
+        architecture: { amd64: {} },
+        country: Array.from(Buffer.from("SG")),
+        cpuCores: 16,
+        gpuCores: 1,
+        ramGb: 64,
+        iops: 10_000,
+        storageGb: 1_000,
+        endpoint: "https://node.example",
+      })
       .accounts({
-        nodeAccount,
-        owner: provider.wallet.publicKey,
+        legacyNode,
+        legacyConfig: config,
+        registryStakeVault: stakeVault,
+        hyperMint,
+        legacyStakeVault,
+        node,
+        operator,
+        authority: owner,
+        migrationAuthority,
+        legacyProgram: program.programId,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .rpc();
 
-    const nodeAfter = await program.account.nodeAccount.fetch(nodeAccount);
-    const heartbeatAfter = nodeAfter.lastHeartbeat.toNumber();
+    // Legacy account closed; active and unbonding stake kept apart
+    assert.isNull(await provider.connection.getAccountInfo(legacyNode));
+    let migrated = await nodesProgram.account.node.fetch(node);
+    assert.equal(migrated.legacyStake.toNumber(), 700);
+    assert.equal(migrated.legacyUnstaking.toNumber(), 300);
+
+    const withdraw = () =>
+      nodesProgram.methods
+        .withdrawLegacyStake()
+        .accounts({
+          node,
+          legacyStakeVault,
+          authorityTokenAccount: ownerTokenAccount,
+          migrationAuthority,
+          authority: owner,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    // Unbonding part: withdrawable once its legacy cooldown has passed
+    await sleep((UNSTAKE_COOLDOWN + 2) * 1000);
+    let before = await getAccount(provider.connection, ownerTokenAccount);
+    await withdraw();
+    let after = await getAccount(provider.connection, ownerTokenAccount);
+    assert.equal(Number(after.amount - before.amount), 300);
+
+    // Active part: only a deactivated node can start its cooldown
+    const unstakeLegacy = () =>
+      nodesProgram.methods
+        .unstakeLegacyStake()
+        .accounts({ node, legacyConfig: config, authority: owner })
+        .rpc();
+
+    await expectError(unstakeLegacy(), "NodeNotDeactivated");
+    await nodesProgram.methods
+      .deactivate()
+      .accounts({ node, authority: owner })
+      .rpc();
+    await unstakeLegacy();
+
+    migrated = await nodesProgram.account.node.fetch(node);
+    assert.equal(migrated.legacyStake.toNumber(), 0);
+    assert.equal(migrated.legacyUnstaking.toNumber(), 700);
+
+    await expectError(withdraw(), "LegacyStakeLocked");
+    await sleep((UNSTAKE_COOLDOWN + 2) * 1000);
+    before = await getAccount(provider.connection, ownerTokenAccount);
+    await withdraw();
+    after = await getAccount(provider.connection, ownerTokenAccount);
+    assert.equal(Number(after.amount - before.amount), 700);
+
+    migrated = await nodesProgram.account.node.fetch(node);
+    assert.equal(migrated.legacyUnstaking.toNumber(), 0);
+  });
+
+  it("Rejects new stake once registration is closed again", async () => {
+    const nodeId = "reclosed-" + Date.now();
+    await register(nodeId);
+    await setRegistrationOpen(false);
 
-    assert.isTrue(heartbeatAfter > heartbeatBefore);
+    await expectError(stake(nodeId, 1_000), "RegistrationClosed");
+    await expectError(register("late-" + Date.now()), "RegistrationClosed");
   });
 });